@@ -0,0 +1,203 @@
+//! # Collections
+//! Group submissions of a subreddit into collections.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::collection::response::DisplayLayout;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let me = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .login()
+//!         .await
+//!         .unwrap();
+//!
+//!     let collections = me.collections();
+//!
+//!     let collection = collections
+//!         .create("t5_2s7lj", "AMAs", Some("All AMAs of 2024"), Some(DisplayLayout::Timeline))
+//!         .await
+//!         .unwrap();
+//!
+//!     collections.add_post(&collection.collection_id, "t3_abc123").await.unwrap();
+//! }
+//! ```
+
+pub mod response;
+
+use serde::Serialize;
+
+use crate::client::{Client, Response};
+use crate::models::collection::response::{Collection, DisplayLayout};
+use crate::util::{url, RouxError};
+
+/// Collections
+#[derive(Debug, Clone)]
+pub struct Collections {
+    client: Client,
+}
+
+impl Collections {
+    /// Create a new `Collections` instance using an oauth client.
+    pub fn new(client: &Client) -> Collections {
+        Collections {
+            client: client.to_owned(),
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn get(&self, url: &str) -> Result<Response, RouxError> {
+        let get_url = url::build_oauth_endpoint(url);
+
+        let response = self.client.get(&get_url[..]).send().await?;
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
+        let post_url = url::build_oauth_endpoint(url);
+
+        let response = self.client.post(&post_url[..]).form(&form).send().await?;
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Get a collection, optionally with its submissions.
+    #[maybe_async::maybe_async]
+    pub async fn collection(&self, id: &str, include_links: bool) -> Result<Collection, RouxError> {
+        let url = format!(
+            "api/v1/collections/collection?collection_id={}&include_links={}",
+            id, include_links
+        );
+
+        Ok(self.get(&url).await?.json::<Collection>().await?)
+    }
+
+    /// List the collections of a subreddit by its fullname.
+    #[maybe_async::maybe_async]
+    pub async fn subreddit_collections(
+        &self,
+        sr_fullname: &str,
+    ) -> Result<Vec<Collection>, RouxError> {
+        let url = format!(
+            "api/v1/collections/subreddit_collections?sr_fullname={}",
+            sr_fullname
+        );
+
+        Ok(self.get(&url).await?.json::<Vec<Collection>>().await?)
+    }
+
+    /// Create a collection in a subreddit by its fullname.
+    #[maybe_async::maybe_async]
+    pub async fn create(
+        &self,
+        sr_fullname: &str,
+        title: &str,
+        description: Option<&str>,
+        display_layout: Option<DisplayLayout>,
+    ) -> Result<Collection, RouxError> {
+        let mut form = vec![
+            ("sr_fullname", sr_fullname),
+            ("title", title),
+            ("description", description.unwrap_or_default()),
+        ];
+
+        if let Some(display_layout) = display_layout {
+            form.push(("display_layout", display_layout.as_str()));
+        }
+
+        Ok(self
+            .post("api/v1/collections/create_collection", &form)
+            .await?
+            .json::<Collection>()
+            .await?)
+    }
+
+    /// Delete a collection.
+    #[maybe_async::maybe_async]
+    pub async fn delete(&self, id: &str) -> Result<Response, RouxError> {
+        let form = [("collection_id", id)];
+        self.post("api/v1/collections/delete_collection", &form)
+            .await
+    }
+
+    /// Add a submission by fullname to a collection.
+    #[maybe_async::maybe_async]
+    pub async fn add_post(&self, id: &str, link_fullname: &str) -> Result<Response, RouxError> {
+        let form = [("collection_id", id), ("link_fullname", link_fullname)];
+        self.post("api/v1/collections/add_post_to_collection", &form)
+            .await
+    }
+
+    /// Remove a submission by fullname from a collection.
+    #[maybe_async::maybe_async]
+    pub async fn remove_post(&self, id: &str, link_fullname: &str) -> Result<Response, RouxError> {
+        let form = [("collection_id", id), ("link_fullname", link_fullname)];
+        self.post("api/v1/collections/remove_post_in_collection", &form)
+            .await
+    }
+
+    /// Set the order of the submissions of a collection by fullname.
+    #[maybe_async::maybe_async]
+    pub async fn reorder(&self, id: &str, link_fullnames: &[&str]) -> Result<Response, RouxError> {
+        let form = [
+            ("collection_id", id.to_owned()),
+            ("link_ids", link_fullnames.join(",")),
+        ];
+        self.post("api/v1/collections/reorder_collection", &form)
+            .await
+    }
+
+    /// Change the title of a collection.
+    #[maybe_async::maybe_async]
+    pub async fn update_title(&self, id: &str, title: &str) -> Result<Response, RouxError> {
+        let form = [("collection_id", id), ("title", title)];
+        self.post("api/v1/collections/update_collection_title", &form)
+            .await
+    }
+
+    /// Change the description of a collection.
+    #[maybe_async::maybe_async]
+    pub async fn update_description(
+        &self,
+        id: &str,
+        description: &str,
+    ) -> Result<Response, RouxError> {
+        let form = [("collection_id", id), ("description", description)];
+        self.post("api/v1/collections/update_collection_description", &form)
+            .await
+    }
+
+    /// Change the layout of a collection, `None` resets it to the default.
+    #[maybe_async::maybe_async]
+    pub async fn update_display_layout(
+        &self,
+        id: &str,
+        display_layout: Option<DisplayLayout>,
+    ) -> Result<Response, RouxError> {
+        let form = [
+            ("collection_id", id),
+            (
+                "display_layout",
+                display_layout.map_or("", |layout| layout.as_str()),
+            ),
+        ];
+        self.post("api/v1/collections/update_collection_display_layout", &form)
+            .await
+    }
+}
@@ -0,0 +1,82 @@
+//! # Collection Responses
+use serde::Deserialize;
+
+use crate::models::Submissions;
+
+/// How the submissions of a collection are shown.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DisplayLayout {
+    /// Ordered by date
+    Timeline,
+    /// Tiles
+    Gallery,
+}
+
+impl DisplayLayout {
+    /// Gets the request string for the layout
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DisplayLayout::Timeline => "TIMELINE",
+            DisplayLayout::Gallery => "GALLERY",
+        }
+    }
+}
+
+/// A group of submissions in a subreddit.
+#[derive(Debug, Deserialize)]
+pub struct Collection {
+    /// ID
+    pub collection_id: String,
+    /// The fullname of the subreddit
+    pub subreddit_id: String,
+    /// Title
+    pub title: String,
+    /// Description
+    #[serde(default)]
+    pub description: String,
+    /// Name of the author
+    pub author_name: String,
+    /// Fullname of the author
+    pub author_id: String,
+    /// Created (UTC)
+    pub created_at_utc: f64,
+    /// Last updated (UTC)
+    pub last_update_utc: f64,
+    /// Layout, `None` for the default one
+    pub display_layout: Option<DisplayLayout>,
+    /// Permalink
+    pub permalink: String,
+    /// Fullnames of the submissions, in order
+    #[serde(default)]
+    pub link_ids: Vec<String>,
+    /// The submissions, if requested
+    pub sorted_links: Option<Submissions>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Collection, DisplayLayout};
+
+    #[test]
+    fn test_deserialize_collection() {
+        let json = r#"{
+            "collection_id": "a1b2",
+            "subreddit_id": "t5_2s7lj",
+            "title": "AMAs",
+            "description": "",
+            "author_name": "spez",
+            "author_id": "t2_1w72",
+            "created_at_utc": 1700000000.0,
+            "last_update_utc": 1700000100.0,
+            "display_layout": "TIMELINE",
+            "permalink": "https://www.reddit.com/r/rust/collection/a1b2",
+            "link_ids": ["t3_x"],
+            "sorted_links": {"kind": "Listing", "data": {"modhash": null, "dist": null, "after": null, "before": null, "children": []}}
+        }"#;
+
+        let collection = serde_json::from_str::<Collection>(json).unwrap();
+        assert_eq!(collection.display_layout, Some(DisplayLayout::Timeline));
+        assert!(collection.sorted_links.unwrap().data.children.is_empty());
+    }
+}
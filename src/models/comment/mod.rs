@@ -1,6 +1,8 @@
 //! # Subreddit Comment Responses
 use serde::{Deserialize, Serialize};
 
+use crate::models::moderation::response::{ModReport, UserReport};
+use crate::models::richtext::RichtextSpan;
 use crate::models::{reply::MaybeReplies, response::BasicListing};
 
 /// SubredditCommentsData
@@ -79,6 +81,10 @@ pub struct CommentData {
     pub permalink: Option<String>,
     /// Number of reports
     pub num_reports: Option<i32>,
+    /// User reports
+    pub user_reports: Option<Vec<UserReport>>,
+    /// Mod reports
+    pub mod_reports: Option<Vec<ModReport>>,
     /// Permalink
     pub link_permalink: Option<String>,
     /// Author link
@@ -87,6 +93,8 @@ pub struct CommentData {
     pub subreddit_name_prefixed: Option<String>,
     /// Author flair
     pub author_flair_text: Option<String>,
+    /// Author richtext flair
+    pub author_flair_richtext: Option<Vec<RichtextSpan>>,
     /// Link url
     pub link_url: Option<String>,
     /// Created
@@ -0,0 +1,255 @@
+//! # Emoji
+//! Manage the custom emoji of a subreddit.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::emoji::EmojiOption;
+//! use roux::stylesheet::ImageFormat;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let subreddit = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .subreddit("rust")
+//!         .await
+//!         .unwrap();
+//!
+//!     let emoji = subreddit.emoji();
+//!
+//!     let image = std::fs::read("ferris.png").unwrap();
+//!     let options = EmojiOption::new().mod_flair_only(true);
+//!     emoji.add("ferris", image, ImageFormat::Png, Some(options)).await.unwrap();
+//!
+//!     let all = emoji.list().await.unwrap();
+//!     println!("{:?}", all.subreddit.keys());
+//! }
+//! ```
+
+pub mod response;
+
+use crate::client::{multipart, Client};
+use crate::models::emoji::response::{Emojis, UploadLeaseResponse};
+use crate::models::stylesheet::ImageFormat;
+use crate::util::defaults::default_client;
+use crate::util::RouxError;
+
+/// Options for adding an emoji.
+#[derive(Clone, Debug, Default)]
+pub struct EmojiOption {
+    /// Only moderators can use the emoji in flair.
+    pub mod_flair_only: Option<bool>,
+    /// The emoji can be used in post flair.
+    pub post_flair_allowed: Option<bool>,
+    /// The emoji can be used in user flair.
+    pub user_flair_allowed: Option<bool>,
+}
+
+impl EmojiOption {
+    /// Create a new `EmojiOption` instance.
+    pub fn new() -> EmojiOption {
+        EmojiOption::default()
+    }
+
+    /// Set mod_flair_only param.
+    pub fn mod_flair_only(mut self, mod_flair_only: bool) -> EmojiOption {
+        self.mod_flair_only = Some(mod_flair_only);
+        self
+    }
+
+    /// Set post_flair_allowed param.
+    pub fn post_flair_allowed(mut self, post_flair_allowed: bool) -> EmojiOption {
+        self.post_flair_allowed = Some(post_flair_allowed);
+        self
+    }
+
+    /// Set user_flair_allowed param.
+    pub fn user_flair_allowed(mut self, user_flair_allowed: bool) -> EmojiOption {
+        self.user_flair_allowed = Some(user_flair_allowed);
+        self
+    }
+
+    /// Add the set options to an emoji form.
+    pub fn build_form(self, form: &mut Vec<(&str, String)>) {
+        if let Some(mod_flair_only) = self.mod_flair_only {
+            form.push(("mod_flair_only", mod_flair_only.to_string()));
+        }
+
+        if let Some(post_flair_allowed) = self.post_flair_allowed {
+            form.push(("post_flair_allowed", post_flair_allowed.to_string()));
+        }
+
+        if let Some(user_flair_allowed) = self.user_flair_allowed {
+            form.push(("user_flair_allowed", user_flair_allowed.to_string()));
+        }
+    }
+}
+
+/// Emoji
+pub struct Emoji {
+    /// Name of subreddit.
+    pub subreddit: String,
+    host: String,
+    upload_host: Option<String>,
+    client: Client,
+}
+
+impl Emoji {
+    /// Create a new `Emoji` instance for a subreddit on a host, e.g. `https://oauth.reddit.com`.
+    pub(crate) fn new(subreddit: &str, host: &str, client: &Client) -> Emoji {
+        Emoji {
+            subreddit: subreddit.to_owned(),
+            host: host.to_owned(),
+            upload_host: None,
+            client: client.to_owned(),
+        }
+    }
+
+    /// Send requests to another host, e.g. a local server for testing.
+    pub fn host(mut self, host: &str) -> Emoji {
+        self.host = host.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Upload images to another host instead of the one given by Reddit's upload lease.
+    pub fn upload_host(mut self, host: &str) -> Emoji {
+        self.upload_host = Some(host.to_owned());
+        self
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/api/v1/{}/{}", self.host, self.subreddit, path)
+    }
+
+    /// List the emoji of the subreddit and the ones provided by Reddit.
+    #[maybe_async::maybe_async]
+    pub async fn list(&self) -> Result<Emojis, RouxError> {
+        Ok(self
+            .client
+            .get(&self.url("emojis/all")[..])
+            .send()
+            .await?
+            .json::<Emojis>()
+            .await?)
+    }
+
+    /// Upload an image and add it as an emoji. Names may only contain letters, numbers,
+    /// `-` and `_`.
+    #[maybe_async::maybe_async]
+    pub async fn add(
+        &self,
+        name: &str,
+        image: Vec<u8>,
+        format: ImageFormat,
+        options: Option<EmojiOption>,
+    ) -> Result<(), RouxError> {
+        let s3_key = self.upload_image(name, image, format).await?;
+
+        let mut form = vec![("name", name.to_owned()), ("s3_key", s3_key)];
+
+        if let Some(options) = options {
+            options.build_form(&mut form);
+        }
+
+        let response = self
+            .client
+            .post(&self.url("emoji.json")[..])
+            .form(&form)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Delete an emoji.
+    #[maybe_async::maybe_async]
+    pub async fn delete(&self, name: &str) -> Result<(), RouxError> {
+        let response = self
+            .client
+            .delete(&self.url(&format!("emoji/{}", name))[..])
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Lease an upload slot and upload the image to it, returning the key it is stored under.
+    #[maybe_async::maybe_async]
+    async fn upload_image(
+        &self,
+        name: &str,
+        image: Vec<u8>,
+        format: ImageFormat,
+    ) -> Result<String, RouxError> {
+        let form = [
+            ("filepath", format!("{}.{}", name, format.as_str())),
+            ("mimetype", format.mime_type().to_owned()),
+        ];
+
+        let response = self
+            .client
+            .post(&self.url("emoji_asset_upload_s3.json")[..])
+            .form(&form)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RouxError::Status(response));
+        }
+
+        let lease = response
+            .json::<UploadLeaseResponse>()
+            .await?
+            .s3_upload_lease;
+
+        let key = match lease.key() {
+            Some(key) => key.to_owned(),
+            None => {
+                return Err(RouxError::MissingData(String::from(
+                    "upload lease is missing a key",
+                )))
+            }
+        };
+
+        let upload_url = match self.upload_host {
+            Some(ref host) => host.to_owned(),
+            None if lease.action.starts_with("//") => format!("https:{}", lease.action),
+            None => lease.action.to_owned(),
+        };
+
+        let mut upload = multipart::Form::new();
+
+        for field in lease.fields {
+            upload = upload.text(field.name, field.value);
+        }
+
+        let file = multipart::Part::bytes(image)
+            .file_name(format!("{}.{}", name, format.as_str()))
+            .mime_str(format.mime_type())?;
+
+        // The lease authorizes the upload, so Reddit's credentials must not be sent along.
+        let response = default_client()
+            .post(&upload_url[..])
+            .multipart(upload.part("file", file))
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(key)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+}
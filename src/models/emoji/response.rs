@@ -0,0 +1,112 @@
+//! # Emoji Responses
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// A custom emoji.
+#[derive(Debug, Deserialize)]
+pub struct EmojiData {
+    /// URL of the image
+    pub url: String,
+    /// The fullname of the user that added the emoji
+    pub created_by: Option<String>,
+    /// Only moderators can use the emoji in flair
+    #[serde(default)]
+    pub mod_flair_only: bool,
+    /// Can be used in user flair
+    #[serde(default)]
+    pub user_flair_allowed: bool,
+    /// Can be used in post flair
+    #[serde(default)]
+    pub post_flair_allowed: bool,
+}
+
+/// The emoji available in a subreddit, by name.
+#[derive(Debug, Deserialize)]
+#[serde(from = "HashMap<String, HashMap<String, EmojiData>>")]
+pub struct Emojis {
+    /// Emoji provided by Reddit
+    pub snoomojis: HashMap<String, EmojiData>,
+    /// Emoji added to the subreddit
+    pub subreddit: HashMap<String, EmojiData>,
+}
+
+impl From<HashMap<String, HashMap<String, EmojiData>>> for Emojis {
+    fn from(mut sets: HashMap<String, HashMap<String, EmojiData>>) -> Self {
+        // Subreddit emoji are keyed by the fullname of the subreddit.
+        let snoomojis = sets.remove("snoomojis").unwrap_or_default();
+        let subreddit = sets.into_values().flatten().collect();
+
+        Emojis {
+            snoomojis,
+            subreddit,
+        }
+    }
+}
+
+/// A form field of an upload lease.
+#[derive(Debug, Deserialize)]
+pub struct LeaseField {
+    /// Name
+    pub name: String,
+    /// Value
+    pub value: String,
+}
+
+/// Permission to upload a file to Reddit's storage.
+#[derive(Debug, Deserialize)]
+pub struct UploadLease {
+    /// The URL to upload to, usually without a scheme
+    pub action: String,
+    /// Fields that must be sent along with the file
+    pub fields: Vec<LeaseField>,
+}
+
+impl UploadLease {
+    /// The key the file is stored under.
+    pub fn key(&self) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.name == "key")
+            .map(|field| field.value.as_str())
+    }
+}
+
+/// UploadLeaseResponse
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadLeaseResponse {
+    /// The lease
+    pub s3_upload_lease: UploadLease,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Emojis, UploadLeaseResponse};
+
+    #[test]
+    fn test_deserialize_emojis() {
+        let json = r#"{
+            "snoomojis": {"cake": {"url": "https://emoji.redditmedia.com/cake.png", "created_by": "t2_1"}},
+            "t5_2s7lj": {"ferris": {"url": "https://emoji.redditmedia.com/ferris.png", "mod_flair_only": true}}
+        }"#;
+
+        let emojis = serde_json::from_str::<Emojis>(json).unwrap();
+        assert!(emojis.snoomojis.contains_key("cake"));
+        assert!(emojis.subreddit["ferris"].mod_flair_only);
+    }
+
+    #[test]
+    fn test_deserialize_lease() {
+        let json = r#"{
+            "s3UploadLease": {
+                "action": "//reddit-uploaded-emoji.s3-accelerate.amazonaws.com",
+                "fields": [{"name": "acl", "value": "private"}, {"name": "key", "value": "t5_2s7lj/abc"}]
+            },
+            "websocketUrl": "wss://example"
+        }"#;
+
+        let lease = serde_json::from_str::<UploadLeaseResponse>(json).unwrap();
+        assert_eq!(lease.s3_upload_lease.key(), Some("t5_2s7lj/abc"));
+    }
+}
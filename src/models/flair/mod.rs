@@ -0,0 +1,477 @@
+//! # Flair
+//! Manage the link and user flair of a subreddit.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::flair::{FlairCsvRow, FlairTemplateOption, FlairType};
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let subreddit = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .subreddit("rust")
+//!         .await
+//!         .unwrap();
+//!
+//!     let flair = subreddit.flair();
+//!
+//!     // Create a link flair template and apply it to a post.
+//!     let options = FlairTemplateOption::new().text("Solved").mod_only(true);
+//!     let template = flair.create_template(FlairType::Link, options).await.unwrap();
+//!     flair.select_link_flair("t3_abc123", Some(&template.id), None).await;
+//!
+//!     // Assign user flair in bulk.
+//!     let rows = vec![FlairCsvRow::new("spez", "Admin", "")];
+//!     let results = flair.flair_csv(&rows).await;
+//! }
+//! ```
+
+pub mod response;
+
+extern crate serde_json;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::{Client, Response};
+use crate::models::flair::response::{FlairCsvResult, FlairList, FlairTemplate};
+use crate::models::response::JsonResponse;
+use crate::util::{url, FeedOption, RouxError};
+
+/// The number of rows Reddit accepts in one flair CSV request.
+const FLAIR_CSV_CHUNK_SIZE: usize = 100;
+
+/// Whether a flair is for submissions or users.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FlairType {
+    /// Link flair
+    Link,
+    /// User flair
+    User,
+}
+
+impl FlairType {
+    /// Gets the request string for the flair type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlairType::Link => "LINK_FLAIR",
+            FlairType::User => "USER_FLAIR",
+        }
+    }
+}
+
+/// The color of flair text.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FlairTextColor {
+    /// Light text
+    Light,
+    /// Dark text
+    Dark,
+}
+
+impl FlairTextColor {
+    /// Gets the request string for the text color
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlairTextColor::Light => "light",
+            FlairTextColor::Dark => "dark",
+        }
+    }
+}
+
+/// What a flair may contain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FlairContent {
+    /// Text and emoji
+    All,
+    /// Only emoji
+    Emoji,
+    /// Only text
+    Text,
+}
+
+impl FlairContent {
+    /// Gets the request string for the allowed content
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlairContent::All => "all",
+            FlairContent::Emoji => "emoji",
+            FlairContent::Text => "text",
+        }
+    }
+}
+
+/// Options for creating or updating a flair template.
+#[derive(Clone, Debug, Default)]
+pub struct FlairTemplateOption {
+    /// Text
+    pub text: Option<String>,
+    /// CSS class
+    pub css_class: Option<String>,
+    /// Text color
+    pub text_color: Option<FlairTextColor>,
+    /// Background color as hex code, e.g. `#ff4500`
+    pub background_color: Option<String>,
+    /// Whether users can edit the text
+    pub text_editable: Option<bool>,
+    /// Whether only moderators can use the template
+    pub mod_only: Option<bool>,
+    /// Maximum number of emojis
+    pub max_emojis: Option<u32>,
+    /// What the flair may contain
+    pub allowable_content: Option<FlairContent>,
+}
+
+impl FlairTemplateOption {
+    /// Create a new `FlairTemplateOption` instance.
+    pub fn new() -> FlairTemplateOption {
+        FlairTemplateOption::default()
+    }
+
+    /// Set text param.
+    pub fn text(mut self, text: &str) -> FlairTemplateOption {
+        self.text = Some(text.to_owned());
+        self
+    }
+
+    /// Set css_class param.
+    pub fn css_class(mut self, css_class: &str) -> FlairTemplateOption {
+        self.css_class = Some(css_class.to_owned());
+        self
+    }
+
+    /// Set text_color param.
+    pub fn text_color(mut self, text_color: FlairTextColor) -> FlairTemplateOption {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    /// Set background_color param.
+    pub fn background_color(mut self, background_color: &str) -> FlairTemplateOption {
+        self.background_color = Some(background_color.to_owned());
+        self
+    }
+
+    /// Set text_editable param.
+    pub fn text_editable(mut self, text_editable: bool) -> FlairTemplateOption {
+        self.text_editable = Some(text_editable);
+        self
+    }
+
+    /// Set mod_only param.
+    pub fn mod_only(mut self, mod_only: bool) -> FlairTemplateOption {
+        self.mod_only = Some(mod_only);
+        self
+    }
+
+    /// Set max_emojis param.
+    pub fn max_emojis(mut self, max_emojis: u32) -> FlairTemplateOption {
+        self.max_emojis = Some(max_emojis);
+        self
+    }
+
+    /// Set allowable_content param.
+    pub fn allowable_content(mut self, allowable_content: FlairContent) -> FlairTemplateOption {
+        self.allowable_content = Some(allowable_content);
+        self
+    }
+
+    /// Add the set options to a flair template form.
+    pub fn build_form(self, form: &mut Vec<(&str, String)>) {
+        if let Some(text) = self.text {
+            form.push(("text", text));
+        }
+
+        if let Some(css_class) = self.css_class {
+            form.push(("css_class", css_class));
+        }
+
+        if let Some(text_color) = self.text_color {
+            form.push(("text_color", text_color.as_str().to_owned()));
+        }
+
+        if let Some(background_color) = self.background_color {
+            form.push(("background_color", background_color));
+        }
+
+        if let Some(text_editable) = self.text_editable {
+            form.push(("text_editable", text_editable.to_string()));
+        }
+
+        if let Some(mod_only) = self.mod_only {
+            form.push(("mod_only", mod_only.to_string()));
+        }
+
+        if let Some(max_emojis) = self.max_emojis {
+            form.push(("max_emojis", max_emojis.to_string()));
+        }
+
+        if let Some(allowable_content) = self.allowable_content {
+            form.push(("allowable_content", allowable_content.as_str().to_owned()));
+        }
+    }
+}
+
+/// A row of a flair CSV, assigning flair to a user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlairCsvRow {
+    /// User
+    pub user: String,
+    /// Flair text. An empty text and CSS class removes the flair.
+    pub text: String,
+    /// Flair CSS class
+    pub css_class: String,
+}
+
+impl FlairCsvRow {
+    /// Create a new `FlairCsvRow` instance.
+    pub fn new(user: &str, text: &str, css_class: &str) -> FlairCsvRow {
+        FlairCsvRow {
+            user: user.to_owned(),
+            text: text.to_owned(),
+            css_class: css_class.to_owned(),
+        }
+    }
+
+    /// Format the row as a CSV line.
+    pub fn to_csv_line(&self) -> String {
+        [&self.user, &self.text, &self.css_class]
+            .iter()
+            .map(|field| format!("\"{}\"", field.replace('"', "\"\"")))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+/// Flair
+pub struct Flair {
+    /// Name of subreddit.
+    pub subreddit: String,
+    url: String,
+    client: Client,
+}
+
+impl Flair {
+    /// Create a new `Flair` instance from a subreddit url.
+    pub(crate) fn new(subreddit: &str, url: &str, client: &Client) -> Flair {
+        Flair {
+            subreddit: subreddit.to_owned(),
+            url: url.to_owned(),
+            client: client.to_owned(),
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
+        let post_url = format!("{}/{}", self.url, url);
+
+        match self.client.post(&post_url[..]).form(&form).send().await {
+            Ok(response) => Ok(response),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post_json<T: Serialize>(&self, url: &str, form: T) -> Result<(), RouxError> {
+        self.post(url, form)
+            .await?
+            .json::<JsonResponse<Value>>()
+            .await?
+            .into_result()?;
+
+        Ok(())
+    }
+
+    #[maybe_async::maybe_async]
+    async fn get_templates(&self, ty: &str) -> Result<Vec<FlairTemplate>, RouxError> {
+        Ok(self
+            .client
+            .get(format!("{}/api/{}", self.url, ty))
+            .send()
+            .await?
+            .json::<Vec<FlairTemplate>>()
+            .await?)
+    }
+
+    /// Get the link flair templates.
+    #[maybe_async::maybe_async]
+    pub async fn link_templates(&self) -> Result<Vec<FlairTemplate>, RouxError> {
+        self.get_templates("link_flair_v2").await
+    }
+
+    /// Get the user flair templates.
+    #[maybe_async::maybe_async]
+    pub async fn user_templates(&self) -> Result<Vec<FlairTemplate>, RouxError> {
+        self.get_templates("user_flair_v2").await
+    }
+
+    #[maybe_async::maybe_async]
+    async fn save_template(
+        &self,
+        kind: FlairType,
+        id: Option<&str>,
+        options: FlairTemplateOption,
+    ) -> Result<FlairTemplate, RouxError> {
+        let mut form = vec![
+            ("api_type", String::from("json")),
+            ("flair_type", kind.as_str().to_owned()),
+        ];
+
+        if let Some(id) = id {
+            form.push(("flair_template_id", id.to_owned()));
+        }
+
+        options.build_form(&mut form);
+
+        Ok(self
+            .post("api/flairtemplate_v2", &form)
+            .await?
+            .json::<FlairTemplate>()
+            .await?)
+    }
+
+    /// Create a flair template.
+    #[maybe_async::maybe_async]
+    pub async fn create_template(
+        &self,
+        kind: FlairType,
+        options: FlairTemplateOption,
+    ) -> Result<FlairTemplate, RouxError> {
+        self.save_template(kind, None, options).await
+    }
+
+    /// Update a flair template. Options that are not set keep their current value.
+    #[maybe_async::maybe_async]
+    pub async fn update_template(
+        &self,
+        kind: FlairType,
+        id: &str,
+        options: FlairTemplateOption,
+    ) -> Result<FlairTemplate, RouxError> {
+        self.save_template(kind, Some(id), options).await
+    }
+
+    /// Delete a flair template.
+    #[maybe_async::maybe_async]
+    pub async fn delete_template(&self, id: &str) -> Result<(), RouxError> {
+        let form = [("api_type", "json"), ("flair_template_id", id)];
+        self.post_json("api/deleteflairtemplate", &form).await
+    }
+
+    /// Delete all flair templates of a type.
+    #[maybe_async::maybe_async]
+    pub async fn clear_templates(&self, kind: FlairType) -> Result<(), RouxError> {
+        let form = [("api_type", "json"), ("flair_type", kind.as_str())];
+        self.post_json("api/clearflairtemplates", &form).await
+    }
+
+    /// Set the flair of a submission from a template. `text` is only used if the
+    /// template is editable. Without a template, the flair is removed.
+    #[maybe_async::maybe_async]
+    pub async fn select_link_flair(
+        &self,
+        fullname: &str,
+        template_id: Option<&str>,
+        text: Option<&str>,
+    ) -> Result<(), RouxError> {
+        let mut form = vec![("api_type", "json"), ("link", fullname)];
+
+        if let Some(template_id) = template_id {
+            form.push(("flair_template_id", template_id));
+        }
+
+        if let Some(text) = text {
+            form.push(("text", text));
+        }
+
+        self.post_json("api/selectflair", &form).await
+    }
+
+    /// Set the flair of a user from a template. `text` is only used if the template is
+    /// editable. Without a template, the flair is removed.
+    #[maybe_async::maybe_async]
+    pub async fn select_user_flair(
+        &self,
+        username: &str,
+        template_id: Option<&str>,
+        text: Option<&str>,
+    ) -> Result<(), RouxError> {
+        let mut form = vec![("api_type", "json"), ("name", username)];
+
+        if let Some(template_id) = template_id {
+            form.push(("flair_template_id", template_id));
+        }
+
+        if let Some(text) = text {
+            form.push(("text", text));
+        }
+
+        self.post_json("api/selectflair", &form).await
+    }
+
+    /// Get the flair of users, or of a single user.
+    #[maybe_async::maybe_async]
+    pub async fn flair_list(
+        &self,
+        username: Option<&str>,
+        options: Option<FeedOption>,
+    ) -> Result<FlairList, RouxError> {
+        let url = &mut format!("{}/api/flairlist/.json", self.url);
+
+        options.unwrap_or_default().build_url(url);
+
+        if let Some(username) = username {
+            url.push_str(&format!("name={}&", url::encode(username)));
+        }
+
+        Ok(self
+            .client
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<FlairList>()
+            .await?)
+    }
+
+    /// Assign user flair in bulk. Rows are sent in chunks of 100, the results are
+    /// returned in the same order as the rows.
+    #[maybe_async::maybe_async]
+    pub async fn flair_csv(&self, rows: &[FlairCsvRow]) -> Result<Vec<FlairCsvResult>, RouxError> {
+        let mut results = Vec::with_capacity(rows.len());
+
+        for chunk in rows.chunks(FLAIR_CSV_CHUNK_SIZE) {
+            let csv = chunk
+                .iter()
+                .map(FlairCsvRow::to_csv_line)
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            let form = [("flair_csv", csv)];
+
+            let response = self.post("api/flaircsv", &form).await?;
+
+            if !response.status().is_success() {
+                return Err(RouxError::Status(response));
+            }
+
+            results.extend(response.json::<Vec<FlairCsvResult>>().await?);
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlairCsvRow;
+
+    #[test]
+    fn test_csv_line() {
+        let row = FlairCsvRow::new("spez", "Says \"hi\", often", "admin");
+        assert_eq!(row.to_csv_line(), r#""spez","Says ""hi"", often","admin""#);
+    }
+}
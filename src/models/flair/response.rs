@@ -0,0 +1,78 @@
+//! # Flair Responses
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::models::richtext::RichtextSpan;
+
+/// A link or user flair template.
+#[derive(Debug, Deserialize)]
+pub struct FlairTemplate {
+    /// ID of the template
+    pub id: String,
+    /// Either `text` or `richtext`
+    #[serde(rename = "type")]
+    pub flair_type: Option<String>,
+    /// Text
+    #[serde(default)]
+    pub text: String,
+    /// Richtext
+    #[serde(default)]
+    pub richtext: Vec<RichtextSpan>,
+    /// CSS class
+    #[serde(default)]
+    pub css_class: String,
+    /// Text color, `light` or `dark`
+    pub text_color: Option<String>,
+    /// Background color as hex code, empty for none
+    #[serde(default)]
+    pub background_color: String,
+    /// Whether users can edit the text
+    #[serde(default)]
+    pub text_editable: bool,
+    /// Whether only moderators can use the template
+    #[serde(default)]
+    pub mod_only: bool,
+    /// Maximum number of emojis
+    pub max_emojis: Option<u32>,
+    /// What the flair may contain: `all`, `emoji` or `text`
+    pub allowable_content: Option<String>,
+}
+
+/// The flair of a user.
+#[derive(Debug, Deserialize)]
+pub struct UserFlair {
+    /// User
+    pub user: String,
+    /// Flair text
+    pub flair_text: Option<String>,
+    /// Flair CSS class
+    pub flair_css_class: Option<String>,
+}
+
+/// A page of user flairs.
+#[derive(Debug, Deserialize)]
+pub struct FlairList {
+    /// User flairs
+    pub users: Vec<UserFlair>,
+    /// Use as `after` to get the next page
+    pub next: Option<String>,
+    /// Use as `before` to get the previous page
+    pub prev: Option<String>,
+}
+
+/// The result of one row of a flair CSV.
+#[derive(Debug, Deserialize)]
+pub struct FlairCsvResult {
+    /// Whether the row was applied
+    pub ok: bool,
+    /// What happened, e.g. `added flair for user spez`
+    #[serde(default)]
+    pub status: String,
+    /// Errors by column
+    #[serde(default)]
+    pub errors: HashMap<String, String>,
+    /// Warnings by column
+    #[serde(default)]
+    pub warnings: HashMap<String, String>,
+}
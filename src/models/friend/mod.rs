@@ -1,8 +1,242 @@
-//! # Inbox Reponses
+//! # Subreddit Relationships
+//! Bans, mutes, approved users and moderators of a subreddit.
 use serde::Deserialize;
-/// The response from an add friend request
+
+use crate::models::response::{BasicThing, Listing};
+
+/// The relationship between a user and a subreddit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RelationshipType {
+    /// Banned from the subreddit.
+    Banned,
+    /// Muted from messaging the subreddit's moderators.
+    Muted,
+    /// Approved user.
+    Contributor,
+    /// Moderator. Can only be used to remove a moderator, new moderators must be invited.
+    Moderator,
+    /// Invited to become a moderator.
+    ModeratorInvite,
+    /// Banned from editing the wiki.
+    WikiBanned,
+    /// Approved wiki contributor.
+    WikiContributor,
+}
+
+impl RelationshipType {
+    /// Gets the request string for the relationship
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelationshipType::Banned => "banned",
+            RelationshipType::Muted => "muted",
+            RelationshipType::Contributor => "contributor",
+            RelationshipType::Moderator => "moderator",
+            RelationshipType::ModeratorInvite => "moderator_invite",
+            RelationshipType::WikiBanned => "wikibanned",
+            RelationshipType::WikiContributor => "wikicontributor",
+        }
+    }
+}
+
+/// A moderator permission.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum ModPermission {
+    /// Full permissions.
+    All,
+    /// Manage approved users, bans and mutes.
+    Access,
+    /// Manage chat config.
+    ChatConfig,
+    /// Manage chat operators.
+    ChatOperator,
+    /// Manage settings, sidebar, css and images.
+    Config,
+    /// Manage user and link flair.
+    Flair,
+    /// Access modmail.
+    Mail,
+    /// Approve, remove, lock and sticky posts and comments.
+    Posts,
+    /// Manage wiki pages.
+    Wiki,
+    /// A permission roux does not know about yet.
+    Other(String),
+}
+
+impl ModPermission {
+    /// Gets the request string for the permission
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModPermission::All => "all",
+            ModPermission::Access => "access",
+            ModPermission::ChatConfig => "chat_config",
+            ModPermission::ChatOperator => "chat_operator",
+            ModPermission::Config => "config",
+            ModPermission::Flair => "flair",
+            ModPermission::Mail => "mail",
+            ModPermission::Posts => "posts",
+            ModPermission::Wiki => "wiki",
+            ModPermission::Other(permission) => permission,
+        }
+    }
+
+    /// Build the `permissions` form value for a set of permissions.
+    pub fn build_permissions(permissions: &[ModPermission]) -> String {
+        if permissions.contains(&ModPermission::All) {
+            return String::from("+all");
+        }
+
+        let mut value = String::from("-all");
+
+        for permission in permissions {
+            value.push_str(&format!(",+{}", permission.as_str()));
+        }
+
+        value
+    }
+}
+
+impl From<String> for ModPermission {
+    fn from(permission: String) -> Self {
+        match permission.as_str() {
+            "all" => ModPermission::All,
+            "access" => ModPermission::Access,
+            "chat_config" => ModPermission::ChatConfig,
+            "chat_operator" => ModPermission::ChatOperator,
+            "config" => ModPermission::Config,
+            "flair" => ModPermission::Flair,
+            "mail" => ModPermission::Mail,
+            "posts" => ModPermission::Posts,
+            "wiki" => ModPermission::Wiki,
+            _ => ModPermission::Other(permission),
+        }
+    }
+}
+
+/// Options for adding a relationship.
+#[derive(Clone, Debug, Default)]
+pub struct RelationshipOption {
+    /// Ban duration in days. Bans are permanent if this is not set.
+    pub duration: Option<u32>,
+    /// The ban reason shown to moderators.
+    pub ban_reason: Option<String>,
+    /// The message sent to the banned user.
+    pub ban_message: Option<String>,
+    /// The fullname of the thing the ban is about.
+    pub ban_context: Option<String>,
+    /// A note shown to moderators.
+    pub note: Option<String>,
+    /// Permissions for a moderator invite.
+    pub permissions: Option<Vec<ModPermission>>,
+}
+
+impl RelationshipOption {
+    /// Create a new `RelationshipOption` instance.
+    pub fn new() -> RelationshipOption {
+        RelationshipOption::default()
+    }
+
+    /// Set duration param.
+    pub fn duration(mut self, days: u32) -> RelationshipOption {
+        self.duration = Some(days);
+        self
+    }
+
+    /// Set ban_reason param.
+    pub fn ban_reason(mut self, reason: &str) -> RelationshipOption {
+        self.ban_reason = Some(reason.to_owned());
+        self
+    }
+
+    /// Set ban_message param.
+    pub fn ban_message(mut self, message: &str) -> RelationshipOption {
+        self.ban_message = Some(message.to_owned());
+        self
+    }
+
+    /// Set ban_context param.
+    pub fn ban_context(mut self, fullname: &str) -> RelationshipOption {
+        self.ban_context = Some(fullname.to_owned());
+        self
+    }
+
+    /// Set note param.
+    pub fn note(mut self, note: &str) -> RelationshipOption {
+        self.note = Some(note.to_owned());
+        self
+    }
+
+    /// Set permissions param.
+    pub fn permissions(mut self, permissions: Vec<ModPermission>) -> RelationshipOption {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Add the set options to a relationship form.
+    pub fn build_form(self, form: &mut Vec<(&str, String)>) {
+        if let Some(duration) = self.duration {
+            form.push(("duration", duration.to_string()));
+        }
+
+        if let Some(ban_reason) = self.ban_reason {
+            form.push(("ban_reason", ban_reason));
+        }
+
+        if let Some(ban_message) = self.ban_message {
+            form.push(("ban_message", ban_message));
+        }
+
+        if let Some(ban_context) = self.ban_context {
+            form.push(("ban_context", ban_context));
+        }
+
+        if let Some(note) = self.note {
+            form.push(("note", note));
+        }
+
+        if let Some(permissions) = self.permissions {
+            form.push((
+                "permissions",
+                ModPermission::build_permissions(&permissions),
+            ));
+        }
+    }
+}
+
+/// RelationshipData
 #[derive(Debug, Deserialize)]
-pub struct Friend {
-    /// Was the friend request a success
-    pub success: bool,
+pub struct RelationshipData {
+    /// The name of the user
+    pub name: String,
+    /// The fullname of the user
+    pub id: String,
+    /// The ID of the relationship
+    pub rel_id: Option<String>,
+    /// When the relationship was created (UTC)
+    pub date: f64,
+    /// The note or ban reason, if any
+    pub note: Option<String>,
+    /// Days left until a temporary ban or mute expires
+    pub days_left: Option<u32>,
+}
+
+/// Relationships (banned, muted, contributors, wikibanned and wikicontributors)
+pub type Relationships = BasicThing<Listing<RelationshipData>>;
+
+#[cfg(test)]
+mod tests {
+    use super::ModPermission;
+
+    #[test]
+    fn test_build_permissions() {
+        let permissions = vec![ModPermission::Posts, ModPermission::Mail];
+        assert_eq!(
+            ModPermission::build_permissions(&permissions),
+            "-all,+posts,+mail"
+        );
+
+        let permissions = vec![ModPermission::Wiki, ModPermission::All];
+        assert_eq!(ModPermission::build_permissions(&permissions), "+all");
+    }
 }
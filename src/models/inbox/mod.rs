@@ -1,7 +1,10 @@
 //! # Inbox Reponses
-use serde::Deserialize;
+use std::collections::HashMap;
 
-use crate::models::response::BasicListing;
+use serde::{de, Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::models::response::{BasicListing, BasicThing};
 
 /// InboxItem
 #[derive(Debug, Deserialize)]
@@ -36,7 +39,207 @@ pub struct InboxData {
     pub created_utc: f64,
     /// Context
     pub context: String,
+    /// ID of the first message of the conversation, if this is a reply
+    pub first_message: Option<u64>,
+    /// Fullname of the first message of the conversation, if this is a reply
+    pub first_message_name: Option<String>,
+    /// Replies to this message
+    #[serde(default, deserialize_with = "deserialize_replies")]
+    pub replies: Vec<InboxData>,
+    /// Whether the message was sent as a moderator or admin
+    pub distinguished: Option<String>,
+}
+
+/// Replies are an empty string if there are none.
+fn deserialize_replies<'de, D>(deserializer: D) -> Result<Vec<InboxData>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(Vec::new()),
+        Value::String(s) if s.is_empty() => Ok(Vec::new()),
+        value => Ok(Inbox::deserialize(value)
+            .map_err(de::Error::custom)?
+            .data
+            .children
+            .into_iter()
+            .map(|thing| thing.data)
+            .collect()),
+    }
 }
 
 /// Inbox
 pub type Inbox = BasicListing<InboxData>;
+
+/// Data returned when composing a message.
+#[derive(Debug, Deserialize)]
+pub struct ComposeData {
+    /// The created message
+    #[serde(default)]
+    pub things: Vec<BasicThing<InboxData>>,
+}
+
+/// A conversation of private messages.
+#[derive(Debug)]
+pub struct MessageThread {
+    /// Fullname of the first message
+    pub id: String,
+    /// Subject of the first message
+    pub subject: String,
+    /// Messages of the conversation, oldest first
+    pub messages: Vec<InboxData>,
+}
+
+/// Groups private messages, including their replies, into conversations.
+/// Threads are ordered by their latest message, newest first.
+pub fn threads(inbox: Inbox) -> Vec<MessageThread> {
+    let mut threads: Vec<MessageThread> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut pending: Vec<InboxData> = inbox
+        .data
+        .children
+        .into_iter()
+        .map(|thing| thing.data)
+        .filter(|message| !message.was_comment)
+        .collect();
+
+    while let Some(mut message) = pending.pop() {
+        pending.append(&mut message.replies);
+
+        let root = message
+            .first_message_name
+            .clone()
+            .unwrap_or_else(|| message.name.clone());
+
+        let thread = *index.entry(root.clone()).or_insert_with(|| {
+            threads.push(MessageThread {
+                id: root,
+                subject: String::new(),
+                messages: Vec::new(),
+            });
+            threads.len() - 1
+        });
+
+        let thread = &mut threads[thread];
+
+        if thread.messages.iter().all(|seen| seen.name != message.name) {
+            thread.messages.push(message);
+        }
+    }
+
+    for thread in threads.iter_mut() {
+        thread
+            .messages
+            .sort_by(|a, b| a.created_utc.total_cmp(&b.created_utc));
+
+        if let Some(first) = thread.messages.first() {
+            thread.subject = first.subject.trim_start_matches("re: ").to_owned();
+        }
+    }
+
+    threads.sort_by(|a, b| {
+        let latest = |thread: &MessageThread| thread.messages.last().map_or(0.0, |m| m.created_utc);
+        latest(b).total_cmp(&latest(a))
+    });
+
+    threads
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{threads, ComposeData, Inbox};
+    use crate::models::response::JsonResponse;
+    use crate::util::RouxError;
+
+    fn message(name: &str, first: Option<&str>, created: u32, replies: &str) -> String {
+        format!(
+            r#"{{"kind": "t4", "data": {{
+                "id": "{id}", "name": "{name}", "subject": "{subject}", "was_comment": false,
+                "author": "someone", "new": false, "type": "unknown", "body": "hi", "dest": "me",
+                "body_html": "", "created": {created}, "created_utc": {created}, "context": "",
+                "first_message_name": {first}, "distinguished": null, "replies": {replies}
+            }}}}"#,
+            id = &name[3..],
+            name = name,
+            subject = if first.is_some() {
+                "re: hello"
+            } else {
+                "hello"
+            },
+            created = created,
+            first = first.map_or(String::from("null"), |first| format!("\"{}\"", first)),
+            replies = replies,
+        )
+    }
+
+    #[test]
+    fn test_threads() {
+        let reply = message("t4_b", Some("t4_a"), 2, r#""""#);
+        let replies = format!(
+            r#"{{"kind": "Listing", "data": {{"after": null, "before": null, "children": [{}]}}}}"#,
+            reply
+        );
+        let listing = format!(
+            r#"{{"kind": "Listing", "data": {{"after": null, "before": null, "children": [{}, {}, {}]}}}}"#,
+            message("t4_c", Some("t4_a"), 3, r#""""#),
+            message("t4_d", None, 1, r#""""#),
+            message("t4_a", None, 0, &replies),
+        );
+
+        let inbox: Inbox = serde_json::from_str(&listing).unwrap();
+        assert_eq!(inbox.data.children[2].data.replies.len(), 1);
+
+        let threads = threads(inbox);
+        assert_eq!(threads.len(), 2);
+
+        assert_eq!(threads[0].id, "t4_a");
+        assert_eq!(threads[0].subject, "hello");
+        let names: Vec<&str> = threads[0]
+            .messages
+            .iter()
+            .map(|message| message.name.as_str())
+            .collect();
+        assert_eq!(names, ["t4_a", "t4_b", "t4_c"]);
+
+        assert_eq!(threads[1].id, "t4_d");
+        assert_eq!(threads[1].messages.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_replies() {
+        let replies = r#"{"kind": "Listing", "data": {"children": [{"kind": "t4", "data": {}}]}}"#;
+        let listing = format!(
+            r#"{{"kind": "Listing", "data": {{"after": null, "before": null, "children": [{}]}}}}"#,
+            message("t4_a", None, 0, replies)
+        );
+        assert!(serde_json::from_str::<Inbox>(&listing).is_err());
+
+        let listing = format!(
+            r#"{{"kind": "Listing", "data": {{"after": null, "before": null, "children": [{}]}}}}"#,
+            message("t4_a", None, 0, r#""not empty""#)
+        );
+        assert!(serde_json::from_str::<Inbox>(&listing).is_err());
+
+        let listing = format!(
+            r#"{{"kind": "Listing", "data": {{"after": null, "before": null, "children": [{}]}}}}"#,
+            message("t4_a", None, 0, "null")
+        );
+        let inbox: Inbox = serde_json::from_str(&listing).unwrap();
+        assert!(inbox.data.children[0].data.replies.is_empty());
+    }
+
+    #[test]
+    fn test_compose_errors() {
+        let json =
+            r#"{"json": {"errors": [["USER_DOESNT_EXIST", "that user doesn't exist", "to"]]}}"#;
+        let response: JsonResponse<ComposeData> = serde_json::from_str(json).unwrap();
+
+        match response.into_result() {
+            Err(RouxError::Api(errors)) => {
+                assert_eq!(errors[0].code, "USER_DOESNT_EXIST");
+                assert_eq!(errors[0].field.as_deref(), Some("to"));
+            }
+            _ => panic!("expected an api error"),
+        }
+    }
+}
@@ -6,12 +6,73 @@ pub mod response;
 extern crate reqwest;
 extern crate serde_json;
 use serde::Serialize;
+use serde_json::Value;
 
 use crate::client::{Client, Response};
 use crate::config::Config;
-use crate::models::me::response::MeData;
-use crate::models::{Friend, Inbox, Saved};
-use crate::util::{url, FeedOption, RouxError};
+use crate::models::friend::{ModPermission, RelationshipOption, RelationshipType};
+use crate::models::inbox::{ComposeData, InboxData};
+use crate::models::me::response::{
+    KarmaList, MeData, Preferences, SubredditKarma, Trophy, TrophyList,
+};
+use crate::models::response::JsonResponse;
+use crate::models::search::response::SearchResults;
+use crate::models::subreddit::response::{SubredditData, SubredditsData};
+use crate::models::{
+    Collections, Inbox, Moderation, Modmail, Multireddit, RichtextDocument, Saved, SearchQuery,
+    Submissions,
+};
+use crate::util::{url, FeedOption, RouxError, SubmitOption};
+
+/// The sort of the front page.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrontPageSort {
+    /// Personalized recommendations
+    Best,
+    /// Hot
+    Hot,
+    /// New
+    New,
+    /// Top, use `FeedOption::period` to set the time period
+    Top,
+    /// Rising
+    Rising,
+    /// Controversial, use `FeedOption::period` to set the time period
+    Controversial,
+}
+
+impl FrontPageSort {
+    /// Gets the request string for the sort
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FrontPageSort::Best => "best",
+            FrontPageSort::Hot => "hot",
+            FrontPageSort::New => "new",
+            FrontPageSort::Top => "top",
+            FrontPageSort::Rising => "rising",
+            FrontPageSort::Controversial => "controversial",
+        }
+    }
+}
+
+/// The recipient of a private message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Recipient {
+    /// A user, by username
+    User(String),
+    /// The moderators of a subreddit, by name
+    Subreddit(String),
+}
+
+impl Recipient {
+    /// Gets the request string for the recipient
+    pub fn to_param(&self) -> String {
+        match self {
+            Recipient::User(username) => username.to_owned(),
+            Recipient::Subreddit(name) => format!("/r/{}", name),
+        }
+    }
+}
 
 /// Me
 #[derive(Debug, Clone)]
@@ -51,6 +112,37 @@ impl Me {
         }
     }
 
+    #[maybe_async::maybe_async]
+    async fn post_ok<T: Serialize>(&self, url: &str, form: T) -> Result<(), RouxError> {
+        let response = self.post(url, form).await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Get a moderation handle for a submission or comment by fullname.
+    pub fn moderation(&self, fullname: &str) -> Moderation {
+        Moderation::new(fullname, &self.client)
+    }
+
+    /// Get a handle to the collections of the subreddits you moderate.
+    pub fn collections(&self) -> Collections {
+        Collections::new(&self.client)
+    }
+
+    /// Get an authenticated handle to a multireddit.
+    pub fn multireddit(&self, user: &str, name: &str) -> Multireddit {
+        Multireddit::new_oauth(user, name, &self.client)
+    }
+
+    /// Get a handle to the modmail of the subreddits you moderate.
+    pub fn modmail(&self) -> Modmail {
+        Modmail::new(&self.client)
+    }
+
     /// Get me
     #[maybe_async::maybe_async]
     pub async fn me(&self) -> Result<MeData, RouxError> {
@@ -60,6 +152,61 @@ impl Me {
         }
     }
 
+    /// Get your account preferences.
+    #[maybe_async::maybe_async]
+    pub async fn prefs(&self) -> Result<Preferences, RouxError> {
+        Ok(self
+            .get("api/v1/me/prefs")
+            .await?
+            .json::<Preferences>()
+            .await?)
+    }
+
+    /// Change the preferences that are set in `patch`, returning all preferences.
+    #[maybe_async::maybe_async]
+    pub async fn update_prefs(&self, patch: &Preferences) -> Result<Preferences, RouxError> {
+        let form = [("json", serde_json::to_string(patch)?)];
+
+        let response = self
+            .client
+            .patch(url::build_oauth_endpoint("api/v1/me/prefs"))
+            .form(&form)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(response.json::<Preferences>().await?)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Get your karma per subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn karma(&self) -> Result<Vec<SubredditKarma>, RouxError> {
+        Ok(self
+            .get("api/v1/me/karma")
+            .await?
+            .json::<KarmaList>()
+            .await?
+            .data)
+    }
+
+    /// Get your trophies.
+    #[maybe_async::maybe_async]
+    pub async fn trophies(&self) -> Result<Vec<Trophy>, RouxError> {
+        Ok(self
+            .get("api/v1/me/trophies")
+            .await?
+            .json::<TrophyList>()
+            .await?
+            .data
+            .trophies
+            .into_iter()
+            .map(|thing| thing.data)
+            .collect())
+    }
+
     /// Submit link
     #[maybe_async::maybe_async]
     pub async fn submit_link(
@@ -101,54 +248,146 @@ impl Me {
     pub async fn submit_richtext(
         &self,
         title: &str,
-        richtext: &str,
+        richtext: &RichtextDocument,
         sr: &str,
     ) -> Result<Response, RouxError> {
+        let richtext = serde_json::to_string(richtext)?;
         let form = [
             ("kind", "self"),
             ("title", title),
-            ("richtext_json", richtext),
+            ("richtext_json", &richtext[..]),
             ("sr", sr),
         ];
 
         self.post("api/submit", &form).await
     }
 
-    /// Adds a friend to a subreddit with the specified type
+    /// Crosspost an existing submission (by fullname, e.g. `t3_abc123`) to another subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn crosspost(
+        &self,
+        source_fullname: &str,
+        target_sr: &str,
+        title: &str,
+        options: Option<SubmitOption>,
+    ) -> Result<Response, RouxError> {
+        let mut form = vec![
+            ("api_type", String::from("json")),
+            ("kind", String::from("crosspost")),
+            ("crosspost_fullname", source_fullname.to_owned()),
+            ("title", title.to_owned()),
+            ("sr", target_sr.to_owned()),
+        ];
+
+        if let Some(options) = options {
+            options.build_form(&mut form);
+        }
+
+        self.post("api/submit", &form).await
+    }
+
+    /// Adds a relationship (ban, mute, approved user, moderator invite...) between a user
+    /// and a subreddit.
     #[maybe_async::maybe_async]
     pub async fn add_subreddit_friend(
         &self,
         username: &str,
-        typ: &str,
+        typ: RelationshipType,
         sub: &str,
-    ) -> Result<bool, RouxError> {
-        let form = [("name", username), ("type", typ)];
-        Ok(self
-            .post(format!("r/{}/api/friend", sub).as_str(), form)
+        options: Option<RelationshipOption>,
+    ) -> Result<(), RouxError> {
+        let mut form = vec![
+            ("api_type", String::from("json")),
+            ("name", username.to_owned()),
+            ("type", typ.as_str().to_owned()),
+        ];
+
+        if let Some(options) = options {
+            options.build_form(&mut form);
+        }
+
+        self.post(format!("r/{}/api/friend", sub).as_str(), &form)
             .await?
-            .json::<Friend>()
+            .json::<JsonResponse<Value>>()
             .await?
-            .success)
+            .into_result()?;
+
+        Ok(())
     }
 
-    /// Removes a friend to a subreddit with the specified type
+    /// Removes a relationship between a user and a subreddit.
     #[maybe_async::maybe_async]
     pub async fn remove_subreddit_friend(
         &self,
         username: &str,
-        typ: &str,
+        typ: RelationshipType,
         sub: &str,
-    ) -> Result<bool, RouxError> {
-        let form = [("name", username), ("type", typ)];
-        Ok(self
-            .post(format!("r/{}/api/unfriend", sub).as_str(), form)
+    ) -> Result<(), RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("name", username),
+            ("type", typ.as_str()),
+        ];
+
+        self.post(format!("r/{}/api/unfriend", sub).as_str(), &form)
             .await?
-            .json::<Friend>()
+            .json::<JsonResponse<Value>>()
             .await?
-            .success)
+            .into_result()?;
+
+        Ok(())
+    }
+
+    /// Sets the permissions of a moderator, or of a pending moderator invite.
+    #[maybe_async::maybe_async]
+    pub async fn set_moderator_permissions(
+        &self,
+        username: &str,
+        sub: &str,
+        permissions: &[ModPermission],
+        invite: bool,
+    ) -> Result<(), RouxError> {
+        let typ = if invite {
+            RelationshipType::ModeratorInvite
+        } else {
+            RelationshipType::Moderator
+        };
+
+        let form = [
+            ("api_type", String::from("json")),
+            ("name", username.to_owned()),
+            ("type", typ.as_str().to_owned()),
+            ("permissions", ModPermission::build_permissions(permissions)),
+        ];
+
+        self.post(format!("r/{}/api/setpermissions", sub).as_str(), &form)
+            .await?
+            .json::<JsonResponse<Value>>()
+            .await?
+            .into_result()?;
+
+        Ok(())
+    }
+
+    /// Accepts a pending moderator invite to a subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn accept_moderator_invite(&self, sub: &str) -> Result<(), RouxError> {
+        let form = [("api_type", "json")];
+
+        self.post(
+            format!("r/{}/api/accept_moderator_invite", sub).as_str(),
+            &form,
+        )
+        .await?
+        .json::<JsonResponse<Value>>()
+        .await?
+        .into_result()?;
+
+        Ok(())
     }
 
     /// Compose message
+    /// Use `compose` to message subreddits, send as a subreddit or get structured errors.
     #[maybe_async::maybe_async]
     pub async fn compose_message(
         &self,
@@ -166,10 +405,205 @@ impl Me {
         self.post("api/compose", &form).await
     }
 
-    /// Get user's submitted posts.
+    /// Send a private message to a user or to the modmail of a subreddit.
+    /// Set `from_sr` to send it as a subreddit you moderate.
+    /// Returns the created message, if Reddit sent it back.
+    /// Errors like `USER_DOESNT_EXIST` or `NO_SUBJECT` are returned as `RouxError::Api`.
+    #[maybe_async::maybe_async]
+    pub async fn compose(
+        &self,
+        to: &Recipient,
+        subject: &str,
+        body: &str,
+        from_sr: Option<&str>,
+    ) -> Result<Option<InboxData>, RouxError> {
+        let to = to.to_param();
+        let mut form = vec![
+            ("api_type", "json"),
+            ("subject", subject),
+            ("text", body),
+            ("to", &to),
+        ];
+
+        if let Some(from_sr) = from_sr {
+            form.push(("from_sr", from_sr));
+        }
+
+        let data = self
+            .post("api/compose", &form)
+            .await?
+            .json::<JsonResponse<ComposeData>>()
+            .await?
+            .into_result()?;
+
+        Ok(data.and_then(|data| data.things.into_iter().next().map(|thing| thing.data)))
+    }
+
+    #[maybe_async::maybe_async]
+    async fn get_inbox(&self, ty: &str, options: Option<FeedOption>) -> Result<Inbox, RouxError> {
+        let url = &mut format!("message/{}", ty);
+
+        options.unwrap_or_default().build_url(url);
+
+        Ok(self.get(url).await?.json::<Inbox>().await?)
+    }
+
+    /// Get all messages, comment replies and mentions you received.
+    #[maybe_async::maybe_async]
+    pub async fn inbox(&self, options: Option<FeedOption>) -> Result<Inbox, RouxError> {
+        self.get_inbox("inbox", options).await
+    }
+
+    /// Get private messages you received.
+    /// Use `inbox::threads` to group them into conversations.
+    #[maybe_async::maybe_async]
+    pub async fn messages(&self, options: Option<FeedOption>) -> Result<Inbox, RouxError> {
+        self.get_inbox("messages", options).await
+    }
+
+    /// Get private messages you sent.
+    #[maybe_async::maybe_async]
+    pub async fn sent(&self, options: Option<FeedOption>) -> Result<Inbox, RouxError> {
+        self.get_inbox("sent", options).await
+    }
+
+    /// Get comments mentioning your username.
+    #[maybe_async::maybe_async]
+    pub async fn mentions(&self, options: Option<FeedOption>) -> Result<Inbox, RouxError> {
+        self.get_inbox("mentions", options).await
+    }
+
+    /// Get replies to your comments.
+    #[maybe_async::maybe_async]
+    pub async fn comment_replies(&self, options: Option<FeedOption>) -> Result<Inbox, RouxError> {
+        self.get_inbox("comments", options).await
+    }
+
+    /// Get replies to your posts.
+    #[maybe_async::maybe_async]
+    pub async fn post_replies(&self, options: Option<FeedOption>) -> Result<Inbox, RouxError> {
+        self.get_inbox("selfreply", options).await
+    }
+
+    /// Get the front page, made of the subreddits you are subscribed to.
+    #[maybe_async::maybe_async]
+    pub async fn front_page(
+        &self,
+        sort: FrontPageSort,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        let url = &mut String::from(sort.as_str());
+
+        options.unwrap_or_default().build_url(url);
+
+        Ok(self.get(url).await?.json::<Submissions>().await?)
+    }
+
+    /// Search site-wide, including results only visible to you.
+    #[maybe_async::maybe_async]
+    pub async fn search(
+        &self,
+        query: &SearchQuery,
+        options: Option<FeedOption>,
+    ) -> Result<SearchResults, RouxError> {
+        let url = &mut String::from("search");
+
+        options.unwrap_or_default().build_url(url);
+        query.build_url(url);
+
+        Ok(self.get(url).await?.json::<SearchResults>().await?)
+    }
+
     #[maybe_async::maybe_async]
-    pub async fn inbox(&self) -> Result<Inbox, RouxError> {
-        Ok(self.get("message/inbox").await?.json::<Inbox>().await?)
+    async fn get_my_subreddits(&self, ty: &str) -> Result<Vec<SubredditData>, RouxError> {
+        let mut subreddits = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let url = &mut format!("subreddits/mine/{}", ty);
+            let mut options = FeedOption::new().limit(100);
+
+            if let Some(after) = &after {
+                options = options.after(after);
+            }
+
+            options.build_url(url);
+
+            let page = self.get(url).await?.json::<SubredditsData>().await?;
+
+            subreddits.extend(page.data.children.into_iter().map(|thing| thing.data));
+
+            match page.data.after {
+                Some(next) => after = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(subreddits)
+    }
+
+    /// Get all subreddits you are subscribed to.
+    #[maybe_async::maybe_async]
+    pub async fn subscriptions(&self) -> Result<Vec<SubredditData>, RouxError> {
+        self.get_my_subreddits("subscriber").await
+    }
+
+    /// Get all subreddits you moderate.
+    #[maybe_async::maybe_async]
+    pub async fn moderated_subreddits(&self) -> Result<Vec<SubredditData>, RouxError> {
+        self.get_my_subreddits("moderator").await
+    }
+
+    /// Get all subreddits you are an approved user of.
+    #[maybe_async::maybe_async]
+    pub async fn contributor_subreddits(&self) -> Result<Vec<SubredditData>, RouxError> {
+        self.get_my_subreddits("contributor").await
+    }
+
+    #[maybe_async::maybe_async]
+    async fn set_subscription(
+        &self,
+        action: &str,
+        subreddits: &[&str],
+        skip_initial_defaults: bool,
+    ) -> Result<(), RouxError> {
+        let (fullnames, names): (Vec<&str>, Vec<&str>) = subreddits
+            .iter()
+            .partition(|subreddit| subreddit.starts_with("t5_"));
+
+        let mut form = vec![("action", action.to_owned())];
+
+        if !fullnames.is_empty() {
+            form.push(("sr", fullnames.join(",")));
+        }
+
+        if !names.is_empty() {
+            form.push(("sr_name", names.join(",")));
+        }
+
+        if skip_initial_defaults {
+            form.push(("skip_initial_defaults", String::from("true")));
+        }
+
+        self.post_ok("api/subscribe", &form).await
+    }
+
+    /// Subscribe to subreddits, given by name or fullname (`t5_...`).
+    /// `skip_initial_defaults` keeps a new account from being subscribed to the default subreddits as well.
+    #[maybe_async::maybe_async]
+    pub async fn subscribe(
+        &self,
+        subreddits: &[&str],
+        skip_initial_defaults: bool,
+    ) -> Result<(), RouxError> {
+        self.set_subscription("sub", subreddits, skip_initial_defaults)
+            .await
+    }
+
+    /// Unsubscribe from subreddits, given by name or fullname (`t5_...`).
+    #[maybe_async::maybe_async]
+    pub async fn unsubscribe(&self, subreddits: &[&str]) -> Result<(), RouxError> {
+        self.set_subscription("unsub", subreddits, false).await
     }
 
     /// Get saved
@@ -219,8 +653,8 @@ impl Me {
 
     /// Get users unread messages
     #[maybe_async::maybe_async]
-    pub async fn unread(&self) -> Result<Inbox, RouxError> {
-        Ok(self.get("message/unread").await?.json::<Inbox>().await?)
+    pub async fn unread(&self, options: Option<FeedOption>) -> Result<Inbox, RouxError> {
+        self.get_inbox("unread", options).await
     }
 
     /// Mark messages as read
@@ -237,6 +671,37 @@ impl Me {
         self.post("api/unread_message", &form).await
     }
 
+    /// Mark all messages as read.
+    #[maybe_async::maybe_async]
+    pub async fn read_all_messages(&self) -> Result<(), RouxError> {
+        let form: [(&str, &str); 0] = [];
+        self.post_ok("api/read_all_messages", &form).await
+    }
+
+    /// Delete a private message you received, by fullname.
+    #[maybe_async::maybe_async]
+    pub async fn del_msg(&self, id: &str) -> Result<(), RouxError> {
+        self.post_ok("api/del_msg", &[("id", id)]).await
+    }
+
+    /// Collapse messages in the inbox, by comma separated fullnames.
+    #[maybe_async::maybe_async]
+    pub async fn collapse_message(&self, ids: &str) -> Result<(), RouxError> {
+        self.post_ok("api/collapse_message", &[("id", ids)]).await
+    }
+
+    /// Expand collapsed messages in the inbox, by comma separated fullnames.
+    #[maybe_async::maybe_async]
+    pub async fn uncollapse_message(&self, ids: &str) -> Result<(), RouxError> {
+        self.post_ok("api/uncollapse_message", &[("id", ids)]).await
+    }
+
+    /// Block the author of a message or comment in your inbox, by fullname.
+    #[maybe_async::maybe_async]
+    pub async fn block(&self, id: &str) -> Result<(), RouxError> {
+        self.post_ok("api/block", &[("id", id)]).await
+    }
+
     /// Comment
     #[maybe_async::maybe_async]
     pub async fn comment(&self, text: &str, parent: &str) -> Result<Response, RouxError> {
@@ -1,11 +1,17 @@
 //! # Me Responses
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::models::response::BasicThing;
+use crate::models::subreddit::response::SubredditData;
 
 /// MeData
 #[derive(Debug, Deserialize)]
 pub struct MeData {
     /// ID
     pub id: String,
+    /// Username
+    pub name: String,
     /// Is employee
     pub is_employee: bool,
     /// Verified
@@ -18,6 +24,8 @@ pub struct MeData {
     pub is_suspended: bool,
     /// Has mail
     pub has_mail: bool,
+    /// Has modmail
+    pub has_mod_mail: Option<bool>,
     /// Inbox count
     pub inbox_count: f64,
     /// Created
@@ -30,10 +38,356 @@ pub struct MeData {
     pub comment_karma: i32,
     /// Link karma
     pub link_karma: i32,
+    /// Awardee karma
+    pub awardee_karma: Option<i32>,
+    /// Awarder karma
+    pub awarder_karma: Option<i32>,
+    /// Total karma
+    pub total_karma: Option<i32>,
     /// Is mod
     pub is_mod: bool,
     /// Is gold
     pub is_gold: bool,
     /// Icon img
     pub icon_img: String,
+    /// Snoovatar img
+    pub snoovatar_img: Option<String>,
+    /// The profile subreddit of the user
+    pub subreddit: Option<SubredditData>,
+    /// Number of friends
+    pub num_friends: Option<u32>,
+    /// Coins
+    pub coins: Option<u32>,
+    /// Has a Reddit Premium subscription
+    pub has_subscribed_to_premium: Option<bool>,
+    /// Reddit Premium expiration (UTC)
+    pub gold_expiration: Option<f64>,
+    /// Hide from robots
+    pub hide_from_robots: Option<bool>,
+    /// Has a password set
+    pub password_set: Option<bool>,
+    /// Must reset the password
+    pub force_password_reset: Option<bool>,
+    /// Accepts followers
+    pub accept_followers: Option<bool>,
+    /// Pref nightmode
+    pub pref_nightmode: Option<bool>,
+    /// Pref no profanity
+    pub pref_no_profanity: Option<bool>,
+    /// Pref show snoovatar
+    pub pref_show_snoovatar: Option<bool>,
+    /// Pref show trending
+    pub pref_show_trending: Option<bool>,
+    /// Pref top karma subreddits
+    pub pref_top_karma_subreddits: Option<bool>,
+    /// Pref autoplay
+    pub pref_autoplay: Option<bool>,
+    /// Pref geopopular
+    pub pref_geopopular: Option<String>,
+    /// Enabled features
+    pub features: Option<Map<String, Value>>,
+}
+
+/// Account preferences.
+///
+/// All fields are optional, so only the set ones are changed by `Me::update_prefs`:
+/// ```
+/// use roux::me::response::Preferences;
+///
+/// let patch = Preferences {
+///     nightmode: Some(true),
+///     ..Default::default()
+/// };
+/// ```
+/// Fields without a typed equivalent are kept in `other`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Preferences {
+    /// Who can send you private messages, `everyone` or `whitelisted`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_pms: Option<String>,
+    /// Allow ads based on your activity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_relevant_ads: Option<bool>,
+    /// Allow reddit to log outbound clicks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_clicktracking: Option<bool>,
+    /// Opt into beta features
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beta: Option<bool>,
+    /// Show recently viewed links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clickgadget: Option<bool>,
+    /// Collapse messages after reading them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapse_read_messages: Option<bool>,
+    /// Compress the link display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<bool>,
+    /// Country code for localized content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    /// Default comment sort, e.g. `confidence`, `top` or `new`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_comment_sort: Option<String>,
+    /// Send email digests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_digests: Option<bool>,
+    /// Email on chat requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_chat_request: Option<bool>,
+    /// Email on comment replies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_comment_reply: Option<bool>,
+    /// Send messages as emails
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_messages: Option<bool>,
+    /// Email on post replies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_post_reply: Option<bool>,
+    /// Email on private messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_private_message: Option<bool>,
+    /// Unsubscribe from all emails
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_unsubscribe_all: Option<bool>,
+    /// Email on username mentions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_username_mention: Option<bool>,
+    /// Allow subreddits to show you their custom themes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_default_themes: Option<bool>,
+    /// Allow people to follow you
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_followers: Option<bool>,
+    /// Region of the popular feed, e.g. `GLOBAL` or `US`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geopopular: Option<String>,
+    /// Hide ads (Reddit Premium)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_ads: Option<bool>,
+    /// Don't show links after downvoting them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_downs: Option<bool>,
+    /// Hide your profile from search engines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_from_robots: Option<bool>,
+    /// Don't show links after upvoting them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_ups: Option<bool>,
+    /// Show a dagger on comments voted controversial
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_controversial: Option<bool>,
+    /// Highlight new comments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_new_comments: Option<bool>,
+    /// Ignore suggested sorts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_suggested_sort: Option<bool>,
+    /// Use the redesign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_redesign_beta: Option<bool>,
+    /// Label posts that are not safe for work
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_nsfw: Option<bool>,
+    /// Interface language, e.g. `en`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    /// Use the legacy search page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legacy_search: Option<bool>,
+    /// Send message notifications in the browser
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_orangereds: Option<bool>,
+    /// Mark messages as read when opening the inbox
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_messages_read: Option<bool>,
+    /// Thumbnails, `on`, `off` or `subreddit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<String>,
+    /// Media previews, `on`, `off` or `subreddit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_preview: Option<String>,
+    /// Don't show comments with a score lower than this
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_comment_score: Option<i32>,
+    /// Don't show links with a score lower than this
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_link_score: Option<i32>,
+    /// Open links in a new window
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub newwindow: Option<bool>,
+    /// Use night mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nightmode: Option<bool>,
+    /// Filter profanity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_profanity: Option<bool>,
+    /// Number of comments to show by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_comments: Option<u32>,
+    /// Number of links to show at once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numsites: Option<u32>,
+    /// Show NSFW content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub over_18: Option<bool>,
+    /// Enable private RSS feeds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_feeds: Option<bool>,
+    /// Opt out of the profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_opt_out: Option<bool>,
+    /// Make your votes public
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_votes: Option<bool>,
+    /// Include NSFW results in searches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_include_over_18: Option<bool>,
+    /// Send welcome messages on joining communities
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_welcome_messages: Option<bool>,
+    /// Show your user flair
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_flair: Option<bool>,
+    /// Show link flair
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_link_flair: Option<bool>,
+    /// Show your online status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_presence: Option<bool>,
+    /// Show your avatar instead of the snoo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_snoovatar: Option<bool>,
+    /// Show custom subreddit styles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_stylesheets: Option<bool>,
+    /// Show trending subreddits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_trending: Option<bool>,
+    /// Show a link to your Twitter account on your profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_twitter: Option<bool>,
+    /// Store visits to links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_visits: Option<bool>,
+    /// Allow personalized ads based on partner data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_party_data_personalized_ads: Option<bool>,
+    /// Allow personalized ads based on third-party site data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_party_site_data_personalized_ads: Option<bool>,
+    /// Allow personalized content based on third-party site data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_party_site_data_personalized_content: Option<bool>,
+    /// Show message conversations in the inbox
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threaded_messages: Option<bool>,
+    /// Show threaded modmail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threaded_modmail: Option<bool>,
+    /// Show the subreddits you have the most karma in on your profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_karma_subreddits: Option<bool>,
+    /// Autoplay videos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_autoplay: Option<bool>,
+    /// Preferences without a typed field
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// Karma of the user in a subreddit.
+#[derive(Debug, Deserialize)]
+pub struct SubredditKarma {
+    /// Name of the subreddit
+    pub sr: String,
+    /// Comment karma
+    pub comment_karma: i32,
+    /// Link karma
+    pub link_karma: i32,
+}
+
+/// Karma breakdown
+pub type KarmaList = BasicThing<Vec<SubredditKarma>>;
+
+/// A trophy of the user.
+#[derive(Debug, Deserialize)]
+pub struct Trophy {
+    /// ID, if the trophy can be awarded more than once
+    pub id: Option<String>,
+    /// Award ID
+    pub award_id: Option<String>,
+    /// Name
+    pub name: String,
+    /// Description
+    pub description: Option<String>,
+    /// Icon, 70x70
+    pub icon_70: Option<String>,
+    /// Icon, 40x40
+    pub icon_40: Option<String>,
+    /// Url the trophy links to
+    pub url: Option<String>,
+    /// Granted at (UTC)
+    pub granted_at: Option<f64>,
+}
+
+/// Trophies
+#[derive(Debug, Deserialize)]
+pub struct TrophyListData {
+    /// Trophies
+    pub trophies: Vec<BasicThing<Trophy>>,
+}
+
+/// Trophy list
+pub type TrophyList = BasicThing<TrophyListData>;
+
+#[cfg(test)]
+mod tests {
+    use super::{KarmaList, Preferences, TrophyList};
+
+    #[test]
+    fn test_preferences_passthrough() {
+        let json = r#"{"nightmode": true, "lang": "en", "some_new_pref": 3}"#;
+        let prefs: Preferences = serde_json::from_str(json).unwrap();
+
+        assert_eq!(prefs.nightmode, Some(true));
+        assert_eq!(prefs.lang.as_deref(), Some("en"));
+        assert_eq!(
+            prefs.other.get("some_new_pref"),
+            Some(&serde_json::json!(3))
+        );
+
+        let patch = Preferences {
+            over_18: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&patch).unwrap(),
+            r#"{"over_18":false}"#
+        );
+        assert_eq!(
+            serde_json::to_value(&prefs).unwrap(),
+            serde_json::from_str::<serde_json::Value>(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_karma_and_trophies() {
+        let karma: KarmaList = serde_json::from_str(
+            r#"{"kind": "KarmaList", "data": [{"sr": "rust", "comment_karma": 12, "link_karma": 3}]}"#,
+        )
+        .unwrap();
+        assert_eq!(karma.data[0].sr, "rust");
+        assert_eq!(karma.data[0].comment_karma, 12);
+
+        let trophies: TrophyList = serde_json::from_str(
+            r#"{"kind": "TrophyList", "data": {"trophies": [
+                {"kind": "t6", "data": {"name": "Verified Email", "id": null, "award_id": "o",
+                "description": null, "icon_70": "https://x/70.png", "icon_40": "https://x/40.png",
+                "url": null, "granted_at": 1600000000}}
+            ]}}"#,
+        )
+        .unwrap();
+        assert_eq!(trophies.data.trophies[0].data.name, "Verified Email");
+    }
 }
@@ -1,26 +1,56 @@
 pub mod about;
+pub mod collection;
 pub mod comment;
+pub mod emoji;
+pub mod flair;
 pub mod friend;
 pub mod inbox;
 pub mod me;
+pub mod moderation;
 pub mod moderator;
+pub mod modlog;
+pub mod modmail;
+pub mod multireddit;
 pub mod overview;
 pub mod reply;
 pub mod response;
+pub mod richtext;
+pub mod rule;
 pub mod saved;
+pub mod search;
+pub mod stylesheet;
 pub mod submission;
 pub mod subreddit;
+pub mod traffic;
 pub mod user;
+pub mod widget;
+pub mod wiki;
 
 pub use about::About;
+pub use collection::Collections;
 pub use comment::Comments;
-pub use friend::Friend;
+pub use emoji::Emoji;
+pub use flair::Flair;
+pub use friend::Relationships;
 pub use inbox::Inbox;
 pub use me::Me;
+pub use moderation::response::ModQueue;
+pub use moderation::Moderation;
 pub use moderator::Moderators;
+pub use modlog::ModLog;
+pub use modmail::Modmail;
+pub use multireddit::Multireddit;
 pub use overview::Overview;
 pub use reply::{MaybeReplies, Replies};
+pub use richtext::RichtextDocument;
+pub use rule::response::Rules;
 pub use saved::Saved;
+pub use search::SearchQuery;
+pub use stylesheet::Stylesheet;
 pub use submission::Submissions;
+pub use subreddit::set::SubredditSet;
 pub use subreddit::{Subreddit, Subreddits};
+pub use traffic::SubredditTraffic;
 pub use user::User;
+pub use widget::Widgets;
+pub use wiki::Wiki;
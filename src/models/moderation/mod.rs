@@ -0,0 +1,306 @@
+//! # Moderation
+//! Moderation actions on a single submission or comment.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::moderation::{DistinguishKind, SuggestedSort};
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let me = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .login()
+//!         .await
+//!         .unwrap();
+//!
+//!     let thread = me.moderation("t3_abc123");
+//!
+//!     thread.lock().await;
+//!     thread.distinguish(DistinguishKind::Yes, false).await;
+//!     thread.set_suggested_sort(Some(SuggestedSort::New)).await;
+//! }
+//! ```
+
+pub mod response;
+
+extern crate serde_json;
+use serde::Serialize;
+
+use crate::client::{Client, Response};
+use crate::util::{url, RouxError};
+
+/// How a submission or comment should be distinguished.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DistinguishKind {
+    /// Distinguish as a moderator.
+    Yes,
+    /// Remove the distinguish.
+    No,
+    /// Distinguish as an admin (admins only).
+    Admin,
+    /// Distinguish with a special flag (admins only).
+    Special,
+}
+
+impl DistinguishKind {
+    /// Gets the request string for the distinguish kind
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistinguishKind::Yes => "yes",
+            DistinguishKind::No => "no",
+            DistinguishKind::Admin => "admin",
+            DistinguishKind::Special => "special",
+        }
+    }
+}
+
+/// The comment sort suggested for a submission.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SuggestedSort {
+    /// Best
+    Confidence,
+    /// Top
+    Top,
+    /// New
+    New,
+    /// Controversial
+    Controversial,
+    /// Old
+    Old,
+    /// Random
+    Random,
+    /// Q&A
+    Qa,
+    /// Live
+    Live,
+}
+
+impl SuggestedSort {
+    /// Gets the request string for the sort
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuggestedSort::Confidence => "confidence",
+            SuggestedSort::Top => "top",
+            SuggestedSort::New => "new",
+            SuggestedSort::Controversial => "controversial",
+            SuggestedSort::Old => "old",
+            SuggestedSort::Random => "random",
+            SuggestedSort::Qa => "qa",
+            SuggestedSort::Live => "live",
+        }
+    }
+}
+
+/// Which sticky slot a submission should take in its subreddit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StickySlot {
+    /// The first (top) sticky slot.
+    First,
+    /// The second (bottom) sticky slot.
+    Second,
+}
+
+impl StickySlot {
+    /// Gets the request string for the slot
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StickySlot::First => "1",
+            StickySlot::Second => "2",
+        }
+    }
+}
+
+/// Restricts a moderation listing to one kind of thing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ThingType {
+    /// Only submissions.
+    Links,
+    /// Only comments.
+    Comments,
+}
+
+impl ThingType {
+    /// Gets the request string for the thing type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThingType::Links => "links",
+            ThingType::Comments => "comments",
+        }
+    }
+}
+
+/// Moderation handle for a submission or comment.
+#[derive(Debug, Clone)]
+pub struct Moderation {
+    /// The fullname of the thing being moderated, e.g. `t3_abc123`.
+    pub fullname: String,
+    client: Client,
+}
+
+impl Moderation {
+    /// Create a new `Moderation` instance using an oauth client.
+    pub fn new(fullname: &str, client: &Client) -> Moderation {
+        Moderation {
+            fullname: fullname.to_owned(),
+            client: client.to_owned(),
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
+        let post_url = url::build_oauth(url);
+
+        match self.client.post(&post_url[..]).form(&form).send().await {
+            Ok(response) => Ok(response),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post_id(&self, url: &str) -> Result<Response, RouxError> {
+        let form = [("id", self.fullname.as_str())];
+        self.post(url, &form).await
+    }
+
+    /// Lock, preventing any new comments.
+    #[maybe_async::maybe_async]
+    pub async fn lock(&self) -> Result<Response, RouxError> {
+        self.post_id("api/lock").await
+    }
+
+    /// Unlock
+    #[maybe_async::maybe_async]
+    pub async fn unlock(&self) -> Result<Response, RouxError> {
+        self.post_id("api/unlock").await
+    }
+
+    /// Sticky a submission in its subreddit. Without a slot, Reddit picks the bottom one.
+    #[maybe_async::maybe_async]
+    pub async fn sticky(&self, slot: Option<StickySlot>) -> Result<Response, RouxError> {
+        let mut form = vec![
+            ("api_type", "json"),
+            ("id", self.fullname.as_str()),
+            ("state", "true"),
+        ];
+
+        if let Some(slot) = slot {
+            form.push(("num", slot.as_str()));
+        }
+
+        self.post("api/set_subreddit_sticky", &form).await
+    }
+
+    /// Unsticky a submission.
+    #[maybe_async::maybe_async]
+    pub async fn unsticky(&self) -> Result<Response, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("id", self.fullname.as_str()),
+            ("state", "false"),
+        ];
+
+        self.post("api/set_subreddit_sticky", &form).await
+    }
+
+    /// Distinguish. `sticky` only applies to top level comments.
+    #[maybe_async::maybe_async]
+    pub async fn distinguish(
+        &self,
+        how: DistinguishKind,
+        sticky: bool,
+    ) -> Result<Response, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("id", self.fullname.as_str()),
+            ("how", how.as_str()),
+            ("sticky", if sticky { "true" } else { "false" }),
+        ];
+
+        self.post("api/distinguish", &form).await
+    }
+
+    /// Mark as NSFW.
+    #[maybe_async::maybe_async]
+    pub async fn mark_nsfw(&self) -> Result<Response, RouxError> {
+        self.post_id("api/marknsfw").await
+    }
+
+    /// Unmark as NSFW.
+    #[maybe_async::maybe_async]
+    pub async fn unmark_nsfw(&self) -> Result<Response, RouxError> {
+        self.post_id("api/unmarknsfw").await
+    }
+
+    /// Mark as a spoiler.
+    #[maybe_async::maybe_async]
+    pub async fn spoiler(&self) -> Result<Response, RouxError> {
+        self.post_id("api/spoiler").await
+    }
+
+    /// Unmark as a spoiler.
+    #[maybe_async::maybe_async]
+    pub async fn unspoiler(&self) -> Result<Response, RouxError> {
+        self.post_id("api/unspoiler").await
+    }
+
+    /// Enable or disable contest mode.
+    #[maybe_async::maybe_async]
+    pub async fn set_contest_mode(&self, state: bool) -> Result<Response, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("id", self.fullname.as_str()),
+            ("state", if state { "true" } else { "false" }),
+        ];
+
+        self.post("api/set_contest_mode", &form).await
+    }
+
+    /// Set the suggested comment sort. `None` clears the suggested sort.
+    #[maybe_async::maybe_async]
+    pub async fn set_suggested_sort(
+        &self,
+        sort: Option<SuggestedSort>,
+    ) -> Result<Response, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("id", self.fullname.as_str()),
+            ("sort", sort.as_ref().map_or("blank", SuggestedSort::as_str)),
+        ];
+
+        self.post("api/set_suggested_sort", &form).await
+    }
+
+    /// Approve
+    #[maybe_async::maybe_async]
+    pub async fn approve(&self) -> Result<Response, RouxError> {
+        self.post_id("api/approve").await
+    }
+
+    /// Remove, optionally marking it as spam.
+    #[maybe_async::maybe_async]
+    pub async fn remove(&self, spam: bool) -> Result<Response, RouxError> {
+        let form = [
+            ("id", self.fullname.as_str()),
+            ("spam", if spam { "true" } else { "false" }),
+        ];
+
+        self.post("api/remove", &form).await
+    }
+
+    /// Ignore future reports.
+    #[maybe_async::maybe_async]
+    pub async fn ignore_reports(&self) -> Result<Response, RouxError> {
+        self.post_id("api/ignore_reports").await
+    }
+
+    /// Stop ignoring reports.
+    #[maybe_async::maybe_async]
+    pub async fn unignore_reports(&self) -> Result<Response, RouxError> {
+        self.post_id("api/unignore_reports").await
+    }
+}
@@ -0,0 +1,160 @@
+//! # Moderation Responses
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::comment::CommentData;
+use crate::models::response::{BasicThing, Listing};
+use crate::models::submission::SubmissionData;
+
+/// A report made by users. Reddit sends these as `[reason, count, snoozed, can_snooze]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserReport {
+    /// The report reason, if one was given.
+    pub reason: Option<String>,
+    /// The number of users that reported with this reason.
+    pub count: u64,
+    /// Whether reports with this reason are snoozed.
+    pub snoozed: bool,
+    /// Whether reports with this reason can be snoozed.
+    pub can_snooze: bool,
+}
+
+impl<'de> Deserialize<'de> for UserReport {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<Value>::deserialize(deserializer)?;
+
+        let count = values
+            .get(1)
+            .and_then(Value::as_u64)
+            .ok_or_else(|| de::Error::custom("user report is missing a count"))?;
+
+        Ok(UserReport {
+            reason: values.first().and_then(Value::as_str).map(str::to_owned),
+            count,
+            snoozed: values.get(2).and_then(Value::as_bool).unwrap_or(false),
+            can_snooze: values.get(3).and_then(Value::as_bool).unwrap_or(false),
+        })
+    }
+}
+
+impl Serialize for UserReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(4))?;
+        seq.serialize_element(&self.reason)?;
+        seq.serialize_element(&self.count)?;
+        seq.serialize_element(&self.snoozed)?;
+        seq.serialize_element(&self.can_snooze)?;
+        seq.end()
+    }
+}
+
+/// A report made by a moderator. Reddit sends these as `[reason, moderator]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModReport {
+    /// The report reason, if one was given.
+    pub reason: Option<String>,
+    /// The name of the moderator that made the report.
+    pub moderator: String,
+}
+
+impl<'de> Deserialize<'de> for ModReport {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<Value>::deserialize(deserializer)?;
+
+        let moderator = values
+            .get(1)
+            .and_then(Value::as_str)
+            .ok_or_else(|| de::Error::custom("mod report is missing a moderator"))?;
+
+        Ok(ModReport {
+            reason: values.first().and_then(Value::as_str).map(str::to_owned),
+            moderator: moderator.to_owned(),
+        })
+    }
+}
+
+impl Serialize for ModReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(2))?;
+        seq.serialize_element(&self.reason)?;
+        seq.serialize_element(&self.moderator)?;
+        seq.end()
+    }
+}
+
+/// An item in a moderation listing can be a comment or post
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum ModQueueData {
+    /// Post
+    #[serde(rename = "t3")]
+    Submission(SubmissionData),
+    /// Comment
+    #[serde(rename = "t1")]
+    Comment(CommentData),
+}
+
+/// Moderation listing (modqueue, reports, spam, edited and unmoderated)
+pub type ModQueue = BasicThing<Listing<ModQueueData>>;
+
+#[cfg(test)]
+mod tests {
+    use super::{ModQueue, ModQueueData, ModReport, UserReport};
+
+    #[test]
+    fn test_deserialize_reports() {
+        let user_reports: Vec<UserReport> =
+            serde_json::from_str(r#"[["Spam", 2, false, true], [null, 1]]"#).unwrap();
+
+        assert_eq!(user_reports[0].reason.as_deref(), Some("Spam"));
+        assert_eq!(user_reports[0].count, 2);
+        assert!(user_reports[0].can_snooze);
+        assert_eq!(user_reports[1].reason, None);
+        assert!(!user_reports[1].snoozed);
+
+        let mod_reports: Vec<ModReport> =
+            serde_json::from_str(r#"[["Rule 1", "some_mod"]]"#).unwrap();
+
+        assert_eq!(mod_reports[0].reason.as_deref(), Some("Rule 1"));
+        assert_eq!(mod_reports[0].moderator, "some_mod");
+    }
+
+    #[test]
+    fn test_deserialize_mod_queue() {
+        let json = r#"{"kind": "Listing", "data": {"after": null, "before": null, "children": [
+            {"kind": "t3", "data": {
+                "subreddit": "rust", "selftext": "", "id": "a", "gilded": 0, "archived": false,
+                "clicked": false, "author": "someone", "score": 1, "over_18": false,
+                "hidden": false, "num_comments": 0, "thumbnail": "self", "subreddit_id": "t5_x",
+                "hide_score": false, "edited": false, "downs": 0, "ups": 1, "upvote_ratio": 1.0,
+                "saved": false, "stickied": false, "is_self": true, "permalink": "/r/rust/a",
+                "locked": false, "name": "t3_a", "created": 0, "quarantine": false,
+                "title": "A post", "created_utc": 0, "visited": false
+            }},
+            {"kind": "t1", "data": {"id": "b", "name": "t1_b", "body": "A comment"}}
+        ]}}"#;
+
+        let queue: ModQueue = serde_json::from_str(json).unwrap();
+
+        match &queue.data.children[0] {
+            ModQueueData::Submission(submission) => assert_eq!(submission.title, "A post"),
+            _ => panic!("expected a submission"),
+        }
+
+        match &queue.data.children[1] {
+            ModQueueData::Comment(comment) => {
+                assert_eq!(comment.body.as_deref(), Some("A comment"))
+            }
+            _ => panic!("expected a comment"),
+        }
+
+        // A post missing required fields is an error, not a comment.
+        let json = r#"{"kind": "Listing", "data": {"after": null, "before": null, "children": [
+            {"kind": "t3", "data": {"id": "c", "name": "t3_c"}}
+        ]}}"#;
+
+        assert!(serde_json::from_str::<ModQueue>(json).is_err());
+    }
+}
@@ -1,7 +1,8 @@
 //! # Subreddit Moderator Responses
 use serde::Deserialize;
 
-use crate::models::response::BasicListing;
+use crate::models::friend::ModPermission;
+use crate::models::response::{BasicThing, Listing};
 
 /// ModeratorsData
 #[derive(Debug, Deserialize)]
@@ -12,7 +13,16 @@ pub struct ModeratorData {
     pub name: String,
     /// Author flair text
     pub author_flair_text: Option<String>,
+    /// Author flair CSS class
+    pub author_flair_css_class: Option<String>,
+    /// The permissions of the moderator
+    #[serde(default)]
+    pub mod_permissions: Vec<ModPermission>,
+    /// The ID of the relationship
+    pub rel_id: Option<String>,
+    /// When the user became a moderator (UTC)
+    pub date: Option<f64>,
 }
 
 /// Moderators
-pub type Moderators = BasicListing<ModeratorData>;
+pub type Moderators = BasicThing<Listing<ModeratorData>>;
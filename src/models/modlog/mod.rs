@@ -0,0 +1,454 @@
+//! # Moderation Log
+//! Typed entries of a subreddit's moderation log.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::modlog::ModActionType;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let subreddit = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .subreddit("rust")
+//!         .await
+//!         .unwrap();
+//!
+//!     // Get the latest bans made by any moderator.
+//!     let bans = subreddit.mod_log(None, Some(ModActionType::BanUser), None).await;
+//!
+//!     // Poll for new entries.
+//!     let mut stream = subreddit.mod_log_stream(None, None);
+//!     let latest = stream.poll().await;
+//!     let newer = stream.poll().await;
+//! }
+//! ```
+use serde::Deserialize;
+
+use crate::models::response::BasicListing;
+use crate::models::Subreddit;
+use crate::util::{FeedOption, RouxError};
+
+/// The type of a moderator action.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum ModActionType {
+    /// `banuser`
+    BanUser,
+    /// `unbanuser`
+    UnbanUser,
+    /// `spamlink`
+    SpamLink,
+    /// `removelink`
+    RemoveLink,
+    /// `approvelink`
+    ApproveLink,
+    /// `spamcomment`
+    SpamComment,
+    /// `removecomment`
+    RemoveComment,
+    /// `approvecomment`
+    ApproveComment,
+    /// `addmoderator`
+    AddModerator,
+    /// `showcomment`
+    ShowComment,
+    /// `invitemoderator`
+    InviteModerator,
+    /// `uninvitemoderator`
+    UninviteModerator,
+    /// `acceptmoderatorinvite`
+    AcceptModeratorInvite,
+    /// `removemoderator`
+    RemoveModerator,
+    /// `addcontributor`
+    AddContributor,
+    /// `removecontributor`
+    RemoveContributor,
+    /// `editsettings`
+    EditSettings,
+    /// `editflair`
+    EditFlair,
+    /// `distinguish`
+    Distinguish,
+    /// `marknsfw`
+    MarkNsfw,
+    /// `wikibanned`
+    WikiBanned,
+    /// `wikicontributor`
+    WikiContributor,
+    /// `wikiunbanned`
+    WikiUnbanned,
+    /// `wikipagelisted`
+    WikiPageListed,
+    /// `removewikicontributor`
+    RemoveWikiContributor,
+    /// `wikirevise`
+    WikiRevise,
+    /// `wikipermlevel`
+    WikiPermLevel,
+    /// `ignorereports`
+    IgnoreReports,
+    /// `unignorereports`
+    UnignoreReports,
+    /// `setpermissions`
+    SetPermissions,
+    /// `setsuggestedsort`
+    SetSuggestedSort,
+    /// `sticky`
+    Sticky,
+    /// `unsticky`
+    Unsticky,
+    /// `setcontestmode`
+    SetContestMode,
+    /// `unsetcontestmode`
+    UnsetContestMode,
+    /// `lock`
+    Lock,
+    /// `unlock`
+    Unlock,
+    /// `muteuser`
+    MuteUser,
+    /// `unmuteuser`
+    UnmuteUser,
+    /// `createrule`
+    CreateRule,
+    /// `editrule`
+    EditRule,
+    /// `reorderrules`
+    ReorderRules,
+    /// `deleterule`
+    DeleteRule,
+    /// `spoiler`
+    Spoiler,
+    /// `unspoiler`
+    Unspoiler,
+    /// `modmail_enrollment`
+    ModmailEnrollment,
+    /// `community_styling`
+    CommunityStyling,
+    /// `community_widgets`
+    CommunityWidgets,
+    /// `markoriginalcontent`
+    MarkOriginalContent,
+    /// `collections`
+    Collections,
+    /// `events`
+    Events,
+    /// `hidden_award`
+    HiddenAward,
+    /// `add_community_topics`
+    AddCommunityTopics,
+    /// `remove_community_topics`
+    RemoveCommunityTopics,
+    /// `create_scheduled_post`
+    CreateScheduledPost,
+    /// `edit_scheduled_post`
+    EditScheduledPost,
+    /// `delete_scheduled_post`
+    DeleteScheduledPost,
+    /// `submit_scheduled_post`
+    SubmitScheduledPost,
+    /// `edit_post_requirements`
+    EditPostRequirements,
+    /// `invitesubscriber`
+    InviteSubscriber,
+    /// `snoozereport`
+    SnoozeReport,
+    /// `unsnoozereport`
+    UnsnoozeReport,
+    /// `addnote`
+    AddNote,
+    /// `deletenote`
+    DeleteNote,
+    /// `addremovalreason`
+    AddRemovalReason,
+    /// `createremovalreason`
+    CreateRemovalReason,
+    /// `updateremovalreason`
+    UpdateRemovalReason,
+    /// `deleteremovalreason`
+    DeleteRemovalReason,
+    /// `reorderremovalreason`
+    ReorderRemovalReason,
+    /// `reordermoderators`
+    ReorderModerators,
+    /// An action type roux does not know about yet.
+    Other(String),
+}
+
+impl ModActionType {
+    /// Gets the request string for the action type
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModActionType::BanUser => "banuser",
+            ModActionType::UnbanUser => "unbanuser",
+            ModActionType::SpamLink => "spamlink",
+            ModActionType::RemoveLink => "removelink",
+            ModActionType::ApproveLink => "approvelink",
+            ModActionType::SpamComment => "spamcomment",
+            ModActionType::RemoveComment => "removecomment",
+            ModActionType::ApproveComment => "approvecomment",
+            ModActionType::AddModerator => "addmoderator",
+            ModActionType::ShowComment => "showcomment",
+            ModActionType::InviteModerator => "invitemoderator",
+            ModActionType::UninviteModerator => "uninvitemoderator",
+            ModActionType::AcceptModeratorInvite => "acceptmoderatorinvite",
+            ModActionType::RemoveModerator => "removemoderator",
+            ModActionType::AddContributor => "addcontributor",
+            ModActionType::RemoveContributor => "removecontributor",
+            ModActionType::EditSettings => "editsettings",
+            ModActionType::EditFlair => "editflair",
+            ModActionType::Distinguish => "distinguish",
+            ModActionType::MarkNsfw => "marknsfw",
+            ModActionType::WikiBanned => "wikibanned",
+            ModActionType::WikiContributor => "wikicontributor",
+            ModActionType::WikiUnbanned => "wikiunbanned",
+            ModActionType::WikiPageListed => "wikipagelisted",
+            ModActionType::RemoveWikiContributor => "removewikicontributor",
+            ModActionType::WikiRevise => "wikirevise",
+            ModActionType::WikiPermLevel => "wikipermlevel",
+            ModActionType::IgnoreReports => "ignorereports",
+            ModActionType::UnignoreReports => "unignorereports",
+            ModActionType::SetPermissions => "setpermissions",
+            ModActionType::SetSuggestedSort => "setsuggestedsort",
+            ModActionType::Sticky => "sticky",
+            ModActionType::Unsticky => "unsticky",
+            ModActionType::SetContestMode => "setcontestmode",
+            ModActionType::UnsetContestMode => "unsetcontestmode",
+            ModActionType::Lock => "lock",
+            ModActionType::Unlock => "unlock",
+            ModActionType::MuteUser => "muteuser",
+            ModActionType::UnmuteUser => "unmuteuser",
+            ModActionType::CreateRule => "createrule",
+            ModActionType::EditRule => "editrule",
+            ModActionType::ReorderRules => "reorderrules",
+            ModActionType::DeleteRule => "deleterule",
+            ModActionType::Spoiler => "spoiler",
+            ModActionType::Unspoiler => "unspoiler",
+            ModActionType::ModmailEnrollment => "modmail_enrollment",
+            ModActionType::CommunityStyling => "community_styling",
+            ModActionType::CommunityWidgets => "community_widgets",
+            ModActionType::MarkOriginalContent => "markoriginalcontent",
+            ModActionType::Collections => "collections",
+            ModActionType::Events => "events",
+            ModActionType::HiddenAward => "hidden_award",
+            ModActionType::AddCommunityTopics => "add_community_topics",
+            ModActionType::RemoveCommunityTopics => "remove_community_topics",
+            ModActionType::CreateScheduledPost => "create_scheduled_post",
+            ModActionType::EditScheduledPost => "edit_scheduled_post",
+            ModActionType::DeleteScheduledPost => "delete_scheduled_post",
+            ModActionType::SubmitScheduledPost => "submit_scheduled_post",
+            ModActionType::EditPostRequirements => "edit_post_requirements",
+            ModActionType::InviteSubscriber => "invitesubscriber",
+            ModActionType::SnoozeReport => "snoozereport",
+            ModActionType::UnsnoozeReport => "unsnoozereport",
+            ModActionType::AddNote => "addnote",
+            ModActionType::DeleteNote => "deletenote",
+            ModActionType::AddRemovalReason => "addremovalreason",
+            ModActionType::CreateRemovalReason => "createremovalreason",
+            ModActionType::UpdateRemovalReason => "updateremovalreason",
+            ModActionType::DeleteRemovalReason => "deleteremovalreason",
+            ModActionType::ReorderRemovalReason => "reorderremovalreason",
+            ModActionType::ReorderModerators => "reordermoderators",
+            ModActionType::Other(action) => action,
+        }
+    }
+}
+
+impl From<String> for ModActionType {
+    fn from(action: String) -> Self {
+        match action.as_str() {
+            "banuser" => ModActionType::BanUser,
+            "unbanuser" => ModActionType::UnbanUser,
+            "spamlink" => ModActionType::SpamLink,
+            "removelink" => ModActionType::RemoveLink,
+            "approvelink" => ModActionType::ApproveLink,
+            "spamcomment" => ModActionType::SpamComment,
+            "removecomment" => ModActionType::RemoveComment,
+            "approvecomment" => ModActionType::ApproveComment,
+            "addmoderator" => ModActionType::AddModerator,
+            "showcomment" => ModActionType::ShowComment,
+            "invitemoderator" => ModActionType::InviteModerator,
+            "uninvitemoderator" => ModActionType::UninviteModerator,
+            "acceptmoderatorinvite" => ModActionType::AcceptModeratorInvite,
+            "removemoderator" => ModActionType::RemoveModerator,
+            "addcontributor" => ModActionType::AddContributor,
+            "removecontributor" => ModActionType::RemoveContributor,
+            "editsettings" => ModActionType::EditSettings,
+            "editflair" => ModActionType::EditFlair,
+            "distinguish" => ModActionType::Distinguish,
+            "marknsfw" => ModActionType::MarkNsfw,
+            "wikibanned" => ModActionType::WikiBanned,
+            "wikicontributor" => ModActionType::WikiContributor,
+            "wikiunbanned" => ModActionType::WikiUnbanned,
+            "wikipagelisted" => ModActionType::WikiPageListed,
+            "removewikicontributor" => ModActionType::RemoveWikiContributor,
+            "wikirevise" => ModActionType::WikiRevise,
+            "wikipermlevel" => ModActionType::WikiPermLevel,
+            "ignorereports" => ModActionType::IgnoreReports,
+            "unignorereports" => ModActionType::UnignoreReports,
+            "setpermissions" => ModActionType::SetPermissions,
+            "setsuggestedsort" => ModActionType::SetSuggestedSort,
+            "sticky" => ModActionType::Sticky,
+            "unsticky" => ModActionType::Unsticky,
+            "setcontestmode" => ModActionType::SetContestMode,
+            "unsetcontestmode" => ModActionType::UnsetContestMode,
+            "lock" => ModActionType::Lock,
+            "unlock" => ModActionType::Unlock,
+            "muteuser" => ModActionType::MuteUser,
+            "unmuteuser" => ModActionType::UnmuteUser,
+            "createrule" => ModActionType::CreateRule,
+            "editrule" => ModActionType::EditRule,
+            "reorderrules" => ModActionType::ReorderRules,
+            "deleterule" => ModActionType::DeleteRule,
+            "spoiler" => ModActionType::Spoiler,
+            "unspoiler" => ModActionType::Unspoiler,
+            "modmail_enrollment" => ModActionType::ModmailEnrollment,
+            "community_styling" => ModActionType::CommunityStyling,
+            "community_widgets" => ModActionType::CommunityWidgets,
+            "markoriginalcontent" => ModActionType::MarkOriginalContent,
+            "collections" => ModActionType::Collections,
+            "events" => ModActionType::Events,
+            "hidden_award" => ModActionType::HiddenAward,
+            "add_community_topics" => ModActionType::AddCommunityTopics,
+            "remove_community_topics" => ModActionType::RemoveCommunityTopics,
+            "create_scheduled_post" => ModActionType::CreateScheduledPost,
+            "edit_scheduled_post" => ModActionType::EditScheduledPost,
+            "delete_scheduled_post" => ModActionType::DeleteScheduledPost,
+            "submit_scheduled_post" => ModActionType::SubmitScheduledPost,
+            "edit_post_requirements" => ModActionType::EditPostRequirements,
+            "invitesubscriber" => ModActionType::InviteSubscriber,
+            "snoozereport" => ModActionType::SnoozeReport,
+            "unsnoozereport" => ModActionType::UnsnoozeReport,
+            "addnote" => ModActionType::AddNote,
+            "deletenote" => ModActionType::DeleteNote,
+            "addremovalreason" => ModActionType::AddRemovalReason,
+            "createremovalreason" => ModActionType::CreateRemovalReason,
+            "updateremovalreason" => ModActionType::UpdateRemovalReason,
+            "deleteremovalreason" => ModActionType::DeleteRemovalReason,
+            "reorderremovalreason" => ModActionType::ReorderRemovalReason,
+            "reordermoderators" => ModActionType::ReorderModerators,
+            _ => ModActionType::Other(action),
+        }
+    }
+}
+
+/// A single entry of the moderation log.
+#[derive(Debug, Deserialize)]
+pub struct ModAction {
+    /// ID of the entry, e.g. `ModAction_0a1b2c3d-...`
+    pub id: String,
+    /// The action that was taken.
+    pub action: ModActionType,
+    /// The name of the moderator that took the action.
+    #[serde(rename = "mod")]
+    pub moderator: String,
+    /// The ID of the moderator (without the `t2_` prefix).
+    pub mod_id36: Option<String>,
+    /// The fullname of the thing the action was taken on.
+    pub target_fullname: Option<String>,
+    /// The author of the thing the action was taken on.
+    pub target_author: Option<String>,
+    /// The permalink of the thing the action was taken on.
+    pub target_permalink: Option<String>,
+    /// The title of the submission the action was taken on.
+    pub target_title: Option<String>,
+    /// The body of the thing the action was taken on.
+    pub target_body: Option<String>,
+    /// Details of the action, e.g. the ban duration or the setting that was changed.
+    pub details: Option<String>,
+    /// A description of the action, e.g. the ban reason.
+    pub description: Option<String>,
+    /// Subreddit
+    pub subreddit: Option<String>,
+    /// Sub name
+    pub subreddit_name_prefixed: Option<String>,
+    /// Created (UTC)
+    pub created_utc: f64,
+}
+
+/// Moderation log
+pub type ModLog = BasicListing<ModAction>;
+
+/// Polls a subreddit's moderation log for new entries.
+pub struct ModLogStream<'a> {
+    subreddit: &'a Subreddit,
+    moderator: Option<String>,
+    action: Option<ModActionType>,
+    newest: Option<String>,
+}
+
+impl<'a> ModLogStream<'a> {
+    /// Create a new `ModLogStream` instance.
+    pub fn new(
+        subreddit: &'a Subreddit,
+        moderator: Option<&str>,
+        action: Option<ModActionType>,
+    ) -> ModLogStream<'a> {
+        ModLogStream {
+            subreddit,
+            moderator: moderator.map(str::to_owned),
+            action,
+            newest: None,
+        }
+    }
+
+    /// Get entries created since the last call, oldest first.
+    /// The first call returns the latest page of the log.
+    #[maybe_async::maybe_async]
+    pub async fn poll(&mut self) -> Result<Vec<ModAction>, RouxError> {
+        let mut options = FeedOption::new().limit(100);
+
+        if let Some(newest) = &self.newest {
+            options = options.before(newest);
+        }
+
+        let log = self
+            .subreddit
+            .mod_log(
+                self.moderator.as_deref(),
+                self.action.clone(),
+                Some(options),
+            )
+            .await?;
+
+        let mut actions: Vec<ModAction> = log
+            .data
+            .children
+            .into_iter()
+            .map(|thing| thing.data)
+            .collect();
+
+        if let Some(newest) = actions.first() {
+            self.newest = Some(newest.id.to_owned());
+        }
+
+        actions.reverse();
+
+        Ok(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModActionType;
+
+    #[test]
+    fn test_action_type_round_trip() {
+        let action = ModActionType::from(String::from("wikirevise"));
+        assert_eq!(action, ModActionType::WikiRevise);
+        assert_eq!(action.as_str(), "wikirevise");
+
+        let unknown = ModActionType::from(String::from("some_new_action"));
+        assert_eq!(
+            unknown,
+            ModActionType::Other(String::from("some_new_action"))
+        );
+        assert_eq!(unknown.as_str(), "some_new_action");
+    }
+}
@@ -0,0 +1,389 @@
+//! # Modmail
+//! Access to the new modmail of the subreddits you moderate.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::modmail::{ModmailSort, ModmailState};
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let me = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .login()
+//!         .await
+//!         .unwrap();
+//!
+//!     let modmail = me.modmail();
+//!
+//!     // Get new conversations of r/rust.
+//!     let conversations = modmail
+//!         .conversations(Some(ModmailState::New), Some(ModmailSort::Recent), Some(&["rust"]), None)
+//!         .await
+//!         .unwrap();
+//!
+//!     // Reply to the first one.
+//!     let id = &conversations.conversation_ids[0];
+//!     modmail.reply(id, "Thanks for reaching out!", false, false).await;
+//! }
+//! ```
+
+pub mod response;
+
+extern crate serde_json;
+use serde::Serialize;
+
+use crate::client::{Client, RequestBuilder, Response};
+use crate::models::modmail::response::{ModmailConversations, ModmailThread, ModmailUnreadCount};
+use crate::util::{url, FeedOption, RouxError};
+
+/// The state of modmail conversations to list.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModmailState {
+    /// All conversations except archived ones
+    Default,
+    /// New
+    New,
+    /// In progress
+    InProgress,
+    /// Archived
+    Archived,
+    /// Ban appeals
+    Appeals,
+    /// Join requests
+    JoinRequests,
+    /// Private moderator discussions
+    Mod,
+    /// Automatic notifications
+    Notifications,
+    /// Highlighted
+    Highlighted,
+    /// All conversations
+    All,
+}
+
+impl ModmailState {
+    /// Gets the request string for the state
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModmailState::Default => "default",
+            ModmailState::New => "new",
+            ModmailState::InProgress => "inprogress",
+            ModmailState::Archived => "archived",
+            ModmailState::Appeals => "appeals",
+            ModmailState::JoinRequests => "join_requests",
+            ModmailState::Mod => "mod",
+            ModmailState::Notifications => "notifications",
+            ModmailState::Highlighted => "highlighted",
+            ModmailState::All => "all",
+        }
+    }
+}
+
+/// How modmail conversations are sorted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModmailSort {
+    /// Most recently updated first
+    Recent,
+    /// Most recently updated by a moderator first
+    Mod,
+    /// Most recently updated by a user first
+    User,
+    /// Unread first
+    Unread,
+}
+
+impl ModmailSort {
+    /// Gets the request string for the sort
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModmailSort::Recent => "recent",
+            ModmailSort::Mod => "mod",
+            ModmailSort::User => "user",
+            ModmailSort::Unread => "unread",
+        }
+    }
+}
+
+/// How long a modmail participant is muted for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MuteDuration {
+    /// 3 days
+    ThreeDays,
+    /// 7 days
+    SevenDays,
+    /// 28 days
+    TwentyEightDays,
+}
+
+impl MuteDuration {
+    /// Gets the duration in hours
+    pub fn hours(&self) -> u32 {
+        match self {
+            MuteDuration::ThreeDays => 72,
+            MuteDuration::SevenDays => 168,
+            MuteDuration::TwentyEightDays => 672,
+        }
+    }
+}
+
+/// Modmail
+#[derive(Debug, Clone)]
+pub struct Modmail {
+    client: Client,
+}
+
+impl Modmail {
+    /// Create a new `Modmail` instance using an oauth client.
+    pub fn new(client: &Client) -> Modmail {
+        Modmail {
+            client: client.to_owned(),
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn send(&self, request: RequestBuilder) -> Result<Response, RouxError> {
+        let response = request.send().await?;
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn get(&self, url: &str) -> Result<Response, RouxError> {
+        self.send(self.client.get(url::build_oauth_endpoint(url)))
+            .await
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
+        self.send(self.client.post(url::build_oauth_endpoint(url)).form(&form))
+            .await
+    }
+
+    /// List conversations, optionally limited to some subreddits.
+    /// The `after` option takes a conversation ID.
+    #[maybe_async::maybe_async]
+    pub async fn conversations(
+        &self,
+        state: Option<ModmailState>,
+        sort: Option<ModmailSort>,
+        subreddits: Option<&[&str]>,
+        options: Option<FeedOption>,
+    ) -> Result<ModmailConversations, RouxError> {
+        let url = &mut String::from("api/mod/conversations");
+
+        options.unwrap_or_default().build_url(url);
+
+        if let Some(state) = state {
+            url.push_str(&format!("state={}&", state.as_str()));
+        }
+
+        if let Some(sort) = sort {
+            url.push_str(&format!("sort={}&", sort.as_str()));
+        }
+
+        if let Some(subreddits) = subreddits {
+            url.push_str(&format!("entity={}&", subreddits.join(",")));
+        }
+
+        Ok(self.get(url).await?.json::<ModmailConversations>().await?)
+    }
+
+    /// Get a conversation with its messages and mod actions.
+    #[maybe_async::maybe_async]
+    pub async fn conversation(
+        &self,
+        id: &str,
+        mark_read: bool,
+    ) -> Result<ModmailThread, RouxError> {
+        let url = format!("api/mod/conversations/{}?markRead={}", id, mark_read);
+
+        Ok(self.get(&url).await?.json::<ModmailThread>().await?)
+    }
+
+    /// Reply to a conversation. Internal replies are private moderator notes, hidden
+    /// replies are sent as the subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn reply(
+        &self,
+        id: &str,
+        body: &str,
+        internal: bool,
+        author_hidden: bool,
+    ) -> Result<ModmailThread, RouxError> {
+        let form = [
+            ("body", body),
+            ("isInternal", if internal { "true" } else { "false" }),
+            (
+                "isAuthorHidden",
+                if author_hidden { "true" } else { "false" },
+            ),
+        ];
+
+        Ok(self
+            .post(&format!("api/mod/conversations/{}", id), &form)
+            .await?
+            .json::<ModmailThread>()
+            .await?)
+    }
+
+    /// Create a new conversation from a subreddit. Without a recipient, a private
+    /// moderator discussion is created.
+    #[maybe_async::maybe_async]
+    pub async fn create(
+        &self,
+        subreddit: &str,
+        to: Option<&str>,
+        subject: &str,
+        body: &str,
+        author_hidden: bool,
+    ) -> Result<ModmailThread, RouxError> {
+        let mut form = vec![
+            ("srName", subreddit),
+            ("subject", subject),
+            ("body", body),
+            (
+                "isAuthorHidden",
+                if author_hidden { "true" } else { "false" },
+            ),
+        ];
+
+        if let Some(to) = to {
+            form.push(("to", to));
+        }
+
+        Ok(self
+            .post("api/mod/conversations", &form)
+            .await?
+            .json::<ModmailThread>()
+            .await?)
+    }
+
+    /// Archive a conversation.
+    #[maybe_async::maybe_async]
+    pub async fn archive(&self, id: &str) -> Result<(), RouxError> {
+        self.post(&format!("api/mod/conversations/{}/archive", id), ())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Unarchive a conversation.
+    #[maybe_async::maybe_async]
+    pub async fn unarchive(&self, id: &str) -> Result<(), RouxError> {
+        self.post(&format!("api/mod/conversations/{}/unarchive", id), ())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Highlight a conversation.
+    #[maybe_async::maybe_async]
+    pub async fn highlight(&self, id: &str) -> Result<(), RouxError> {
+        self.post(&format!("api/mod/conversations/{}/highlight", id), ())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove the highlight of a conversation.
+    #[maybe_async::maybe_async]
+    pub async fn unhighlight(&self, id: &str) -> Result<(), RouxError> {
+        let url = url::build_oauth_endpoint(&format!("api/mod/conversations/{}/highlight", id));
+        self.send(self.client.delete(url)).await?;
+
+        Ok(())
+    }
+
+    /// Mute the non-moderator participant of a conversation.
+    #[maybe_async::maybe_async]
+    pub async fn mute(&self, id: &str, duration: MuteDuration) -> Result<(), RouxError> {
+        let form = [("num_hours", duration.hours())];
+
+        self.post(&format!("api/mod/conversations/{}/mute", id), &form)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Unmute the non-moderator participant of a conversation.
+    #[maybe_async::maybe_async]
+    pub async fn unmute(&self, id: &str) -> Result<(), RouxError> {
+        self.post(&format!("api/mod/conversations/{}/unmute", id), ())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Approve the non-moderator participant of a conversation as an approved user.
+    #[maybe_async::maybe_async]
+    pub async fn approve(&self, id: &str) -> Result<(), RouxError> {
+        self.post(&format!("api/mod/conversations/{}/approve", id), ())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove the non-moderator participant of a conversation from the approved users.
+    #[maybe_async::maybe_async]
+    pub async fn disapprove(&self, id: &str) -> Result<(), RouxError> {
+        self.post(&format!("api/mod/conversations/{}/disapprove", id), ())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Mark conversations as read.
+    #[maybe_async::maybe_async]
+    pub async fn mark_read(&self, ids: &[&str]) -> Result<(), RouxError> {
+        let form = [("conversationIds", ids.join(","))];
+        self.post("api/mod/conversations/read", &form).await?;
+
+        Ok(())
+    }
+
+    /// Mark conversations as unread.
+    #[maybe_async::maybe_async]
+    pub async fn mark_unread(&self, ids: &[&str]) -> Result<(), RouxError> {
+        let form = [("conversationIds", ids.join(","))];
+        self.post("api/mod/conversations/unread", &form).await?;
+
+        Ok(())
+    }
+
+    /// Mark all conversations of some subreddits in a state as read.
+    #[maybe_async::maybe_async]
+    pub async fn bulk_mark_read(
+        &self,
+        subreddits: &[&str],
+        state: Option<ModmailState>,
+    ) -> Result<(), RouxError> {
+        let mut form = vec![("entity", subreddits.join(","))];
+
+        if let Some(state) = state {
+            form.push(("state", state.as_str().to_owned()));
+        }
+
+        self.post("api/mod/conversations/bulk/read", &form).await?;
+
+        Ok(())
+    }
+
+    /// Get the number of unread conversations by state.
+    #[maybe_async::maybe_async]
+    pub async fn unread_count(&self) -> Result<ModmailUnreadCount, RouxError> {
+        Ok(self
+            .get("api/mod/conversations/unread/count")
+            .await?
+            .json::<ModmailUnreadCount>()
+            .await?)
+    }
+}
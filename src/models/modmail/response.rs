@@ -0,0 +1,321 @@
+//! # Modmail Responses
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A user taking part in a modmail conversation.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailParticipant {
+    /// ID
+    pub id: Option<u64>,
+    /// Name
+    pub name: String,
+    /// Is mod
+    #[serde(default)]
+    pub is_mod: bool,
+    /// Is admin
+    #[serde(default)]
+    pub is_admin: bool,
+    /// Is the user that started the conversation
+    #[serde(default)]
+    pub is_op: bool,
+    /// Is participant
+    #[serde(default)]
+    pub is_participant: bool,
+    /// Is approved user
+    #[serde(default)]
+    pub is_approved: bool,
+    /// Is hidden (sent as the subreddit)
+    #[serde(default)]
+    pub is_hidden: bool,
+    /// Is deleted
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
+/// The subreddit that owns a modmail conversation.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailOwner {
+    /// The fullname of the subreddit
+    pub id: String,
+    /// The name of the subreddit
+    pub display_name: String,
+    /// Type of the owner, usually `subreddit`
+    #[serde(rename = "type")]
+    pub owner_type: String,
+}
+
+/// A reference to a message or mod action in a conversation.
+#[derive(Debug, Deserialize)]
+pub struct ModmailObjId {
+    /// ID of the object
+    pub id: String,
+    /// Either `messages` or `modActions`
+    pub key: String,
+}
+
+/// ModmailConversation
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversation {
+    /// ID
+    pub id: String,
+    /// Subject
+    pub subject: String,
+    /// State of the conversation: `0` new, `1` in progress, `2` archived,
+    /// `3` appeals, `4` join requests, `5` filtered.
+    pub state: i64,
+    /// Last updated
+    pub last_updated: Option<String>,
+    /// Last updated by the user
+    pub last_user_update: Option<String>,
+    /// Last updated by a moderator
+    pub last_mod_update: Option<String>,
+    /// Last unread
+    pub last_unread: Option<String>,
+    /// Is a private moderator discussion
+    #[serde(default)]
+    pub is_internal: bool,
+    /// Is highlighted
+    #[serde(default)]
+    pub is_highlighted: bool,
+    /// Was created automatically, e.g. a ban notification
+    #[serde(default)]
+    pub is_auto: bool,
+    /// Can be replied to
+    #[serde(default)]
+    pub is_repliable: bool,
+    /// Number of messages
+    pub num_messages: u64,
+    /// The subreddit that owns the conversation
+    pub owner: ModmailOwner,
+    /// The non-moderator participant, if any
+    pub participant: Option<ModmailParticipant>,
+    /// Authors of messages in this conversation
+    #[serde(default)]
+    pub authors: Vec<ModmailParticipant>,
+    /// The messages and mod actions of this conversation, in order
+    #[serde(default)]
+    pub obj_ids: Vec<ModmailObjId>,
+}
+
+/// ModmailMessage
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailMessage {
+    /// ID
+    pub id: String,
+    /// Body HTML
+    pub body: String,
+    /// Body
+    pub body_markdown: String,
+    /// Author
+    pub author: ModmailParticipant,
+    /// Is a private moderator note
+    #[serde(default)]
+    pub is_internal: bool,
+    /// Date
+    pub date: String,
+    /// How the author took part, e.g. `moderator` or `participant_user`
+    pub participating_as: Option<String>,
+}
+
+/// An action taken by a moderator on a conversation.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailAction {
+    /// ID
+    pub id: String,
+    /// Date
+    pub date: String,
+    /// Type of the action: `0` highlight, `1` unhighlight, `2` archive, `3` unarchive,
+    /// `5` mute, `6` unmute, `7` ban, `8` unban, `9` approve, `10` disapprove.
+    pub action_type_id: i64,
+    /// The moderator that took the action
+    pub author: ModmailParticipant,
+}
+
+/// A page of modmail conversations.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversations {
+    /// Conversations by ID
+    pub conversations: HashMap<String, ModmailConversation>,
+    /// Conversation IDs in listing order
+    pub conversation_ids: Vec<String>,
+    /// Latest messages by ID
+    #[serde(default)]
+    pub messages: HashMap<String, ModmailMessage>,
+}
+
+impl ModmailConversations {
+    /// Get the conversations in listing order.
+    pub fn ordered(&self) -> Vec<&ModmailConversation> {
+        self.conversation_ids
+            .iter()
+            .filter_map(|id| self.conversations.get(id))
+            .collect()
+    }
+}
+
+/// A single modmail conversation with its messages and mod actions.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailThread {
+    /// Conversation
+    pub conversation: ModmailConversation,
+    /// Messages by ID
+    #[serde(default)]
+    pub messages: HashMap<String, ModmailMessage>,
+    /// Mod actions by ID
+    #[serde(default)]
+    pub mod_actions: HashMap<String, ModmailAction>,
+    /// Information about the participant, if any
+    pub user: Option<Value>,
+}
+
+impl ModmailThread {
+    /// Get the messages of the conversation in order.
+    pub fn ordered_messages(&self) -> Vec<&ModmailMessage> {
+        self.conversation
+            .obj_ids
+            .iter()
+            .filter(|obj| obj.key == "messages")
+            .filter_map(|obj| self.messages.get(&obj.id))
+            .collect()
+    }
+}
+
+/// Unread modmail conversation counts by state.
+#[derive(Debug, Deserialize)]
+pub struct ModmailUnreadCount {
+    /// New
+    #[serde(default)]
+    pub new: u64,
+    /// In progress
+    #[serde(default)]
+    pub inprogress: u64,
+    /// Archived
+    #[serde(default)]
+    pub archived: u64,
+    /// Appeals
+    #[serde(default)]
+    pub appeals: u64,
+    /// Join requests
+    #[serde(default)]
+    pub join_requests: u64,
+    /// Highlighted
+    #[serde(default)]
+    pub highlighted: u64,
+    /// Mod discussions
+    #[serde(default)]
+    pub r#mod: u64,
+    /// Notifications
+    #[serde(default)]
+    pub notifications: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModmailConversations, ModmailThread};
+
+    const CONVERSATION: &str = r#"{
+        "id": "1x2y3",
+        "subject": "Why was my post removed?",
+        "state": 1,
+        "lastUpdated": "2023-06-01T10:00:00.000000+00:00",
+        "lastUserUpdate": "2023-06-01T09:00:00.000000+00:00",
+        "lastModUpdate": "2023-06-01T10:00:00.000000+00:00",
+        "lastUnread": null,
+        "isInternal": false,
+        "isHighlighted": true,
+        "isAuto": false,
+        "isRepliable": true,
+        "numMessages": 2,
+        "owner": {"id": "t5_2s7lj", "displayName": "rust", "type": "subreddit"},
+        "participant": {"id": 1234, "name": "ferris", "isMod": false, "isAdmin": false,
+            "isOp": true, "isParticipant": true, "isApproved": false, "isHidden": false,
+            "isDeleted": false},
+        "authors": [{"id": 1234, "name": "ferris", "isOp": true, "isParticipant": true}],
+        "objIds": [
+            {"id": "m1", "key": "messages"},
+            {"id": "a1", "key": "modActions"},
+            {"id": "m2", "key": "messages"}
+        ]
+    }"#;
+
+    fn message(id: &str, author: &str, is_mod: bool) -> String {
+        format!(
+            r#"{{"id": "{id}", "body": "<p>{id}</p>", "bodyMarkdown": "{id}",
+                "author": {{"id": 1, "name": "{author}", "isMod": {is_mod}}},
+                "isInternal": false, "date": "2023-06-01T09:00:00.000000+00:00",
+                "participatingAs": "{participating}"}}"#,
+            id = id,
+            author = author,
+            is_mod = is_mod,
+            participating = if is_mod {
+                "moderator"
+            } else {
+                "participant_user"
+            },
+        )
+    }
+
+    #[test]
+    fn test_conversation_list() {
+        let json = format!(
+            r#"{{
+                "conversations": {{"1x2y3": {conversation}}},
+                "conversationIds": ["1x2y3", "gone"],
+                "messages": {{"m2": {message}}},
+                "viewerId": "t2_abc"
+            }}"#,
+            conversation = CONVERSATION,
+            message = message("m2", "modperson", true),
+        );
+
+        let list: ModmailConversations = serde_json::from_str(&json).unwrap();
+        let ordered = list.ordered();
+
+        assert_eq!(ordered.len(), 1);
+        assert_eq!(ordered[0].subject, "Why was my post removed?");
+        assert_eq!(ordered[0].owner.display_name, "rust");
+        assert!(ordered[0].is_highlighted);
+        assert!(ordered[0].participant.as_ref().unwrap().is_op);
+        assert_eq!(list.messages["m2"].author.name, "modperson");
+    }
+
+    #[test]
+    fn test_conversation_with_messages_and_actions() {
+        let json = format!(
+            r#"{{
+                "conversation": {conversation},
+                "messages": {{"m1": {first}, "m2": {second}}},
+                "modActions": {{"a1": {{"id": "a1", "date": "2023-06-01T09:30:00.000000+00:00",
+                    "actionTypeId": 0, "author": {{"id": 2, "name": "modperson", "isMod": true}}}}}},
+                "user": {{"name": "ferris"}}
+            }}"#,
+            conversation = CONVERSATION,
+            first = message("m1", "ferris", false),
+            second = message("m2", "modperson", true),
+        );
+
+        let thread: ModmailThread = serde_json::from_str(&json).unwrap();
+        let bodies: Vec<&str> = thread
+            .ordered_messages()
+            .iter()
+            .map(|message| message.body_markdown.as_str())
+            .collect();
+
+        assert_eq!(bodies, ["m1", "m2"]);
+        assert_eq!(
+            thread.ordered_messages()[1].participating_as.as_deref(),
+            Some("moderator")
+        );
+        assert_eq!(thread.mod_actions["a1"].action_type_id, 0);
+        assert!(thread.mod_actions["a1"].author.is_mod);
+    }
+}
@@ -0,0 +1,432 @@
+//! # Multireddit
+//! Read the feeds of a multireddit and manage your own.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::multireddit::MultiredditOption;
+//! use roux::multireddit::response::Visibility;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let me = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .login()
+//!         .await
+//!         .unwrap();
+//!
+//!     let multi = me.multireddit("USERNAME", "rustlang");
+//!
+//!     let options = MultiredditOption::new()
+//!         .display_name("Rust")
+//!         .visibility(Visibility::Private)
+//!         .subreddits(&["rust", "learnrust"]);
+//!     multi.create(options).await.unwrap();
+//!
+//!     multi.add_subreddit("rust_gamedev").await.unwrap();
+//!     let hot = multi.hot(25, None).await;
+//! }
+//! ```
+
+pub mod response;
+
+extern crate serde_json;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::client::{Client, RequestBuilder, Response};
+use crate::models::multireddit::response::{LabeledMulti, MultiredditData, Visibility};
+use crate::models::Submissions;
+use crate::util::defaults::default_client;
+use crate::util::{FeedOption, RouxError};
+
+/// Options for creating or updating a multireddit.
+#[derive(Clone, Debug, Default)]
+pub struct MultiredditOption {
+    /// Display name.
+    pub display_name: Option<String>,
+    /// Description in **Markdown** format.
+    pub description_md: Option<String>,
+    /// Who can see the multireddit.
+    pub visibility: Option<Visibility>,
+    /// Names of the subreddits.
+    pub subreddits: Option<Vec<String>>,
+    /// Theme color as hex code.
+    pub key_color: Option<String>,
+}
+
+impl MultiredditOption {
+    /// Create a new `MultiredditOption` instance.
+    pub fn new() -> MultiredditOption {
+        MultiredditOption::default()
+    }
+
+    /// Set display_name param.
+    pub fn display_name(mut self, display_name: &str) -> MultiredditOption {
+        self.display_name = Some(display_name.to_owned());
+        self
+    }
+
+    /// Set description_md param.
+    pub fn description(mut self, description: &str) -> MultiredditOption {
+        self.description_md = Some(description.to_owned());
+        self
+    }
+
+    /// Set visibility param.
+    pub fn visibility(mut self, visibility: Visibility) -> MultiredditOption {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Set subreddits param.
+    pub fn subreddits(mut self, subreddits: &[&str]) -> MultiredditOption {
+        self.subreddits = Some(subreddits.iter().map(|name| name.to_string()).collect());
+        self
+    }
+
+    /// Set key_color param.
+    pub fn key_color(mut self, key_color: &str) -> MultiredditOption {
+        self.key_color = Some(key_color.to_owned());
+        self
+    }
+
+    /// Build the JSON `model` of a multireddit request. Only set options are included.
+    pub fn build_model(self) -> String {
+        let mut model = Map::new();
+
+        if let Some(display_name) = self.display_name {
+            model.insert(String::from("display_name"), Value::from(display_name));
+        }
+
+        if let Some(description_md) = self.description_md {
+            model.insert(String::from("description_md"), Value::from(description_md));
+        }
+
+        if let Some(visibility) = self.visibility {
+            model.insert(String::from("visibility"), Value::from(visibility.as_str()));
+        }
+
+        if let Some(subreddits) = self.subreddits {
+            let subreddits = subreddits
+                .into_iter()
+                .map(|name| json!({ "name": name }))
+                .collect();
+            model.insert(String::from("subreddits"), Value::Array(subreddits));
+        }
+
+        if let Some(key_color) = self.key_color {
+            model.insert(String::from("key_color"), Value::from(key_color));
+        }
+
+        Value::Object(model).to_string()
+    }
+}
+
+/// Multireddit
+pub struct Multireddit {
+    /// Name of the owner.
+    pub user: String,
+    /// Name of the multireddit.
+    pub name: String,
+    host: String,
+    client: Client,
+}
+
+impl Multireddit {
+    /// Create a new `Multireddit` instance.
+    pub fn new(user: &str, name: &str) -> Multireddit {
+        Multireddit {
+            user: user.to_owned(),
+            name: name.to_owned(),
+            host: String::from("https://www.reddit.com"),
+            client: default_client(),
+        }
+    }
+
+    /// Create a new authenticated `Multireddit` instance using an oauth client
+    /// from the `Reddit` module.
+    pub fn new_oauth(user: &str, name: &str, client: &Client) -> Multireddit {
+        Multireddit {
+            user: user.to_owned(),
+            name: name.to_owned(),
+            host: String::from("https://oauth.reddit.com"),
+            client: client.to_owned(),
+        }
+    }
+
+    fn path(&self) -> String {
+        format!("user/{}/m/{}", self.user, self.name)
+    }
+
+    #[maybe_async::maybe_async]
+    async fn send(&self, request: RequestBuilder) -> Result<Response, RouxError> {
+        let response = request.send().await?;
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
+        let post_url = format!("{}/{}", self.host, url);
+
+        self.send(self.client.post(&post_url[..]).form(&form)).await
+    }
+
+    #[maybe_async::maybe_async]
+    async fn put<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
+        let put_url = format!("{}/{}", self.host, url);
+
+        self.send(self.client.put(&put_url[..]).form(&form)).await
+    }
+
+    fn feed_url(&self, ty: &str, limit: u32, options: Option<FeedOption>) -> String {
+        let mut url = format!("{}/{}/{}.json", self.host, self.path(), ty);
+
+        options.unwrap_or_default().build_url(&mut url);
+        url.push_str(&format!("limit={}&", limit));
+
+        url
+    }
+
+    #[maybe_async::maybe_async]
+    async fn get_feed(
+        &self,
+        ty: &str,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        let url = self.feed_url(ty, limit, options);
+
+        Ok(self
+            .client
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<Submissions>()
+            .await?)
+    }
+
+    /// Get hot posts.
+    #[maybe_async::maybe_async]
+    pub async fn hot(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_feed("hot", limit, options).await
+    }
+
+    /// Get rising posts.
+    #[maybe_async::maybe_async]
+    pub async fn rising(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_feed("rising", limit, options).await
+    }
+
+    /// Get top posts.
+    #[maybe_async::maybe_async]
+    pub async fn top(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_feed("top", limit, options).await
+    }
+
+    /// Get latest posts.
+    #[maybe_async::maybe_async]
+    pub async fn latest(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_feed("new", limit, options).await
+    }
+
+    /// Get multireddit data.
+    #[maybe_async::maybe_async]
+    pub async fn about(&self) -> Result<MultiredditData, RouxError> {
+        let url = format!("{}/api/multi/{}", self.host, self.path());
+
+        Ok(self
+            .send(self.client.get(&url[..]))
+            .await?
+            .json::<LabeledMulti>()
+            .await?
+            .data)
+    }
+
+    /// Create this multireddit (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn create(&self, options: MultiredditOption) -> Result<MultiredditData, RouxError> {
+        let form = [("model", options.build_model())];
+
+        Ok(self
+            .post(&format!("api/multi/{}", self.path()), &form)
+            .await?
+            .json::<LabeledMulti>()
+            .await?
+            .data)
+    }
+
+    /// Update this multireddit, leaving unset options unchanged (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn update(&self, options: MultiredditOption) -> Result<MultiredditData, RouxError> {
+        let form = [("model", options.build_model())];
+
+        Ok(self
+            .put(&format!("api/multi/{}", self.path()), &form)
+            .await?
+            .json::<LabeledMulti>()
+            .await?
+            .data)
+    }
+
+    /// Copy this multireddit to one of yours named `to_name` (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn copy(
+        &self,
+        to_user: &str,
+        to_name: &str,
+        display_name: Option<&str>,
+    ) -> Result<MultiredditData, RouxError> {
+        let form = [
+            ("from", self.path()),
+            ("to", format!("user/{}/m/{}", to_user, to_name)),
+            ("display_name", display_name.unwrap_or(to_name).to_owned()),
+        ];
+
+        Ok(self
+            .post("api/multi/copy", &form)
+            .await?
+            .json::<LabeledMulti>()
+            .await?
+            .data)
+    }
+
+    /// Rename this multireddit (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn rename(
+        &mut self,
+        new_name: &str,
+        display_name: Option<&str>,
+    ) -> Result<MultiredditData, RouxError> {
+        let form = [
+            ("from", self.path()),
+            ("to", format!("user/{}/m/{}", self.user, new_name)),
+            ("display_name", display_name.unwrap_or(new_name).to_owned()),
+        ];
+
+        let data = self
+            .post("api/multi/rename", &form)
+            .await?
+            .json::<LabeledMulti>()
+            .await?
+            .data;
+
+        self.name = new_name.to_owned();
+
+        Ok(data)
+    }
+
+    /// Delete this multireddit (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn delete(&self) -> Result<(), RouxError> {
+        let url = format!("{}/api/multi/{}", self.host, self.path());
+
+        self.send(self.client.delete(&url[..])).await?;
+
+        Ok(())
+    }
+
+    /// Add a subreddit (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn add_subreddit(&self, subreddit: &str) -> Result<(), RouxError> {
+        let form = [("model", json!({ "name": subreddit }).to_string())];
+
+        self.put(&format!("api/multi/{}/r/{}", self.path(), subreddit), &form)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove a subreddit (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn remove_subreddit(&self, subreddit: &str) -> Result<(), RouxError> {
+        let url = format!("{}/api/multi/{}/r/{}", self.host, self.path(), subreddit);
+
+        self.send(self.client.delete(&url[..])).await?;
+
+        Ok(())
+    }
+
+    /// Change who can see this multireddit (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn set_visibility(
+        &self,
+        visibility: Visibility,
+    ) -> Result<MultiredditData, RouxError> {
+        self.update(MultiredditOption::new().visibility(visibility))
+            .await
+    }
+
+    /// Change the description of this multireddit (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn set_description(&self, description: &str) -> Result<(), RouxError> {
+        let form = [("model", json!({ "body_md": description }).to_string())];
+
+        self.put(&format!("api/multi/{}/description", self.path()), &form)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Multireddit, MultiredditOption};
+    use crate::models::multireddit::response::Visibility;
+    use crate::util::FeedOption;
+
+    #[test]
+    fn test_build_model() {
+        let model = MultiredditOption::new()
+            .visibility(Visibility::Hidden)
+            .subreddits(&["rust", "learnrust"])
+            .build_model();
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&model).unwrap(),
+            serde_json::json!({
+                "visibility": "hidden",
+                "subreddits": [{"name": "rust"}, {"name": "learnrust"}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_feed_url() {
+        let multireddit = Multireddit::new("spez", "news");
+
+        assert_eq!(
+            multireddit.feed_url("hot", 25, Some(FeedOption::new().after("t3_x"))),
+            "https://www.reddit.com/user/spez/m/news/hot.json?&after=t3_x&limit=25&"
+        );
+        assert_eq!(
+            multireddit.feed_url("top", 10, None),
+            "https://www.reddit.com/user/spez/m/news/top.json?&limit=10&"
+        );
+    }
+}
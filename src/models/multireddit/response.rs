@@ -0,0 +1,110 @@
+//! # Multireddit Responses
+use serde::Deserialize;
+
+use crate::models::response::BasicThing;
+
+/// Who can see a multireddit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// Only the owner
+    Private,
+    /// Anyone
+    Public,
+    /// Anyone with the link
+    Hidden,
+}
+
+impl Visibility {
+    /// Gets the request string for the visibility
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Private => "private",
+            Visibility::Public => "public",
+            Visibility::Hidden => "hidden",
+        }
+    }
+}
+
+/// A subreddit in a multireddit.
+#[derive(Debug, Deserialize)]
+pub struct MultiredditSubreddit {
+    /// Name of the subreddit
+    pub name: String,
+}
+
+/// MultiredditData
+#[derive(Debug, Deserialize)]
+pub struct MultiredditData {
+    /// Name used in the path
+    pub name: String,
+    /// Display name
+    pub display_name: String,
+    /// Path, e.g. `/user/spez/m/rust`
+    pub path: String,
+    /// Description in **Markdown** format
+    #[serde(default)]
+    pub description_md: String,
+    /// Who can see the multireddit
+    pub visibility: Visibility,
+    /// Subreddits
+    #[serde(default)]
+    pub subreddits: Vec<MultiredditSubreddit>,
+    /// Name of the owner
+    pub owner: String,
+    /// Fullname of the owner
+    pub owner_id: String,
+    /// Created (UTC)
+    pub created_utc: f64,
+    /// Number of subscribers
+    #[serde(default)]
+    pub num_subscribers: u64,
+    /// Icon
+    pub icon_url: Option<String>,
+    /// Theme color as hex code
+    pub key_color: Option<String>,
+    /// Whether you can edit the multireddit
+    #[serde(default)]
+    pub can_edit: bool,
+    /// Path of the multireddit this was copied from
+    pub copied_from: Option<String>,
+    /// Contains NSFW subreddits
+    #[serde(default)]
+    pub over_18: bool,
+}
+
+/// Multireddit
+pub type LabeledMulti = BasicThing<MultiredditData>;
+
+#[cfg(test)]
+mod tests {
+    use super::{LabeledMulti, Visibility};
+
+    #[test]
+    fn test_deserialize_multireddit() {
+        let json = r##"{
+            "kind": "LabeledMulti",
+            "data": {
+                "name": "rustlang",
+                "display_name": "Rust",
+                "path": "/user/spez/m/rustlang/",
+                "description_md": "",
+                "visibility": "public",
+                "subreddits": [{"name": "rust"}, {"name": "learnrust"}],
+                "owner": "spez",
+                "owner_id": "t2_1w72",
+                "created_utc": 1700000000.0,
+                "num_subscribers": 3,
+                "icon_url": null,
+                "key_color": "#cee3f8",
+                "can_edit": false,
+                "copied_from": null,
+                "over_18": false
+            }
+        }"##;
+
+        let multi = serde_json::from_str::<LabeledMulti>(json).unwrap();
+        assert_eq!(multi.data.visibility, Visibility::Public);
+        assert_eq!(multi.data.subreddits[1].name, "learnrust");
+    }
+}
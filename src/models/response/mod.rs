@@ -3,6 +3,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::util::{ApiError, RouxError};
+
 /// Basic structure of a Reddit response.
 /// See: <https://github.com/reddit-archive/reddit/wiki/JSON>
 #[derive(Serialize, Deserialize, Debug)]
@@ -31,3 +33,32 @@ pub struct Listing<T> {
 
 /// Often times a basic thing will have this structure.
 pub type BasicListing<T> = BasicThing<Listing<BasicThing<T>>>;
+
+/// Response of endpoints called with `api_type=json`.
+#[derive(Deserialize, Debug)]
+pub struct JsonResponse<T> {
+    /// Wrapped response.
+    pub json: JsonData<T>,
+}
+
+/// Errors and data of a `JsonResponse`.
+#[derive(Deserialize, Debug)]
+pub struct JsonData<T> {
+    /// Errors reported by Reddit. Empty if the request succeeded.
+    #[serde(default)]
+    pub errors: Vec<ApiError>,
+    /// Data returned by the endpoint, if any.
+    pub data: Option<T>,
+}
+
+impl<T> JsonResponse<T> {
+    /// Turns the reported errors into a `RouxError::Api`, otherwise returns the data.
+    #[allow(clippy::result_large_err)]
+    pub fn into_result(self) -> Result<Option<T>, RouxError> {
+        if self.json.errors.is_empty() {
+            Ok(self.json.data)
+        } else {
+            Err(RouxError::Api(self.json.errors))
+        }
+    }
+}
@@ -0,0 +1,970 @@
+//! # Richtext Markdown
+//! Conversion between richtext and Reddit flavored Markdown.
+//!
+//! Supported are paragraphs, ATX headings, ordered and unordered lists, indented and
+//! fenced code blocks, quotes, horizontal rules, bold, italic, strikethrough, superscript,
+//! inline code, links, spoilers and `u/` and `r/` mentions.
+
+use crate::models::richtext::{
+    CodeLine, FormatRange, ListItem, RichtextBlock, RichtextDocument, RichtextSpan, TextFormat,
+};
+
+impl RichtextDocument {
+    /// Convert Markdown to a richtext document.
+    pub fn from_markdown(markdown: &str) -> RichtextDocument {
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        RichtextDocument {
+            document: parse_blocks(&lines),
+        }
+    }
+
+    /// Convert the document to Markdown.
+    pub fn to_markdown(&self) -> String {
+        blocks_to_markdown(&self.document)
+    }
+}
+
+/// Convert inline Markdown, e.g. a flair text, to richtext spans.
+pub fn spans_from_markdown(markdown: &str) -> Vec<RichtextSpan> {
+    InlineParser::new(markdown).parse()
+}
+
+/// Convert richtext spans to inline Markdown.
+pub fn spans_to_markdown(spans: &[RichtextSpan]) -> String {
+    spans.iter().map(span_to_markdown).collect()
+}
+
+fn blocks_to_markdown(blocks: &[RichtextBlock]) -> String {
+    blocks
+        .iter()
+        .map(block_to_markdown)
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+fn block_to_markdown(block: &RichtextBlock) -> String {
+    match block {
+        RichtextBlock::Paragraph { children } => {
+            // Keep lines that look like the start of another block from becoming one.
+            spans_to_markdown(children)
+                .split('\n')
+                .map(escape_block_start)
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+        RichtextBlock::Heading { level, children } => format!(
+            "{} {}",
+            "#".repeat(usize::from((*level).clamp(1, 6))),
+            spans_to_markdown(children)
+        ),
+        RichtextBlock::List { ordered, items } => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = if *ordered {
+                    format!("{}. ", i + 1)
+                } else {
+                    String::from("- ")
+                };
+
+                let content = blocks_to_markdown(&item.children);
+                let indent = " ".repeat(marker.len());
+
+                format!("{}{}", marker, indent_lines(&content, &indent))
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        RichtextBlock::CodeBlock { lines } => lines
+            .iter()
+            .map(|line| format!("    {}", line.text))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        RichtextBlock::Blockquote { children } => blocks_to_markdown(children)
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    String::from(">")
+                } else {
+                    format!("> {}", line)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        RichtextBlock::HorizontalRule => String::from("---"),
+        RichtextBlock::Unknown => String::new(),
+    }
+}
+
+/// Indent every line but the first, leaving empty lines empty.
+fn indent_lines(text: &str, indent: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 || line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{}{}", indent, line)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn span_to_markdown(span: &RichtextSpan) -> String {
+    match span {
+        RichtextSpan::Text { text, formats } => formatted_to_markdown(text, formats),
+        RichtextSpan::Link { text, url, .. } => format!("[{}]({})", escape(text), url),
+        RichtextSpan::UserMention { name } => format!("u/{}", name),
+        RichtextSpan::SubredditMention { name } => format!("r/{}", name),
+        RichtextSpan::Emoji { name, .. } => name.to_owned(),
+        RichtextSpan::Spoiler { children } => format!(">!{}!<", spans_to_markdown(children)),
+        RichtextSpan::LineBreak => String::from("  \n"),
+        RichtextSpan::Unknown => String::new(),
+    }
+}
+
+fn formatted_to_markdown(text: &str, formats: &[FormatRange]) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut char_formats = vec![TextFormat::NONE; chars.len()];
+
+    for range in formats {
+        let end = (range.start + range.length).min(chars.len());
+
+        for format in char_formats.iter_mut().take(end).skip(range.start) {
+            *format = *format | range.format;
+        }
+    }
+
+    let mut markdown = String::new();
+    let mut start = 0;
+
+    while start < chars.len() {
+        let format = char_formats[start];
+        let mut end = start;
+
+        while end < chars.len() && char_formats[end] == format {
+            end += 1;
+        }
+
+        let run: String = chars[start..end].iter().collect();
+        markdown.push_str(&wrap_run(&run, format));
+        start = end;
+    }
+
+    markdown
+}
+
+/// Wrap a run of equally formatted text in Markdown markers. Surrounding whitespace is
+/// kept outside of the markers, as Markdown would not recognize them otherwise.
+fn wrap_run(run: &str, format: TextFormat) -> String {
+    if format.is_empty() {
+        return escape(run);
+    }
+
+    let trimmed = run.trim();
+
+    if trimmed.is_empty() {
+        return run.to_owned();
+    }
+
+    let leading = &run[..run.len() - run.trim_start().len()];
+    let trailing = &run[run.trim_end().len()..];
+
+    let mut inner = if format.contains(TextFormat::CODE) {
+        code_span(trimmed)
+    } else {
+        escape(trimmed)
+    };
+
+    if format.contains(TextFormat::SUPERSCRIPT) {
+        inner = format!("^({})", inner);
+    }
+
+    if format.contains(TextFormat::STRIKETHROUGH) {
+        inner = format!("~~{}~~", inner);
+    }
+
+    if format.contains(TextFormat::ITALIC) {
+        inner = format!("*{}*", inner);
+    }
+
+    if format.contains(TextFormat::BOLD) {
+        inner = format!("**{}**", inner);
+    }
+
+    format!("{}{}{}", leading, inner, trailing)
+}
+
+fn escape(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut escaped = String::with_capacity(text.len());
+
+    for (i, c) in chars.iter().enumerate() {
+        // The slash of a `u/` or `r/` prefix, which would otherwise become a mention.
+        let is_mention = *c == '/'
+            && i >= 1
+            && matches!(chars[i - 1], 'u' | 'r')
+            && (i < 2 || !chars[i - 2].is_alphanumeric());
+
+        if is_mention || matches!(c, '\\' | '*' | '_' | '~' | '`' | '^' | '[' | ']' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(*c);
+    }
+
+    escaped
+}
+
+/// Escape the marker of a line that would otherwise start a list, heading or other block.
+fn escape_block_start(line: &str) -> String {
+    if !starts_block(line) {
+        return line.to_owned();
+    }
+
+    let trimmed = line.trim_start();
+    let (indent, marker) = line.split_at(line.len() - trimmed.len());
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+
+    // For ordered list markers, escape the `.` or `)` following the number.
+    let (number, rest) = marker.split_at(digits);
+
+    format!("{}{}\\{}", indent, number, rest)
+}
+
+/// Wrap code in a backtick run longer than any run inside of it.
+fn code_span(code: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+
+    for c in code.chars() {
+        if c == '`' {
+            run += 1;
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+    }
+
+    let fence = "`".repeat(longest + 1);
+
+    // A space keeps backticks at either end from joining the fence.
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{} {} {}", fence, code, fence)
+    } else {
+        format!("{}{}{}", fence, code, fence)
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Count the leading spaces of a line, with tabs counting as four.
+fn indentation(line: &str) -> usize {
+    let mut indent = 0;
+
+    for c in line.chars() {
+        match c {
+            ' ' => indent += 1,
+            '\t' => indent += 4,
+            _ => break,
+        }
+    }
+
+    indent
+}
+
+/// Remove up to `width` columns of indentation.
+fn strip_indent(line: &str, width: usize) -> &str {
+    let mut removed = 0;
+
+    for (i, c) in line.char_indices() {
+        if removed >= width {
+            return &line[i..];
+        }
+
+        match c {
+            ' ' => removed += 1,
+            '\t' => removed += 4,
+            _ => return &line[i..],
+        }
+    }
+
+    ""
+}
+
+fn is_fence(line: &str) -> bool {
+    indentation(line) < 4 && line.trim_start().starts_with("```")
+}
+
+fn is_horizontal_rule(line: &str) -> bool {
+    if indentation(line) >= 4 {
+        return false;
+    }
+
+    let rule: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+
+    rule.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|marker| rule.chars().all(|c| c == *marker))
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    if indentation(line) >= 4 {
+        return None;
+    }
+
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    let rest = &trimmed[level..];
+
+    if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with([' ', '\t'])) {
+        let text = rest.trim().trim_end_matches('#').trim_end();
+        Some((level as u8, text))
+    } else {
+        None
+    }
+}
+
+fn is_quote(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    indentation(line) < 4 && trimmed.starts_with('>') && !trimmed.starts_with(">!")
+}
+
+/// Returns whether the item is ordered and the indentation of its content.
+fn list_marker(line: &str) -> Option<(bool, usize)> {
+    let indent = indentation(line);
+
+    if indent >= 4 || is_horizontal_rule(line) {
+        return None;
+    }
+
+    let trimmed = line.trim_start();
+
+    if trimmed.starts_with(['-', '*', '+']) {
+        let rest = &trimmed[1..];
+
+        if rest.is_empty() || rest.starts_with(' ') {
+            return Some((false, indent + 2));
+        }
+
+        return None;
+    }
+
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+
+    if (1..=9).contains(&digits) {
+        let rest = &trimmed[digits..];
+
+        if rest.starts_with(['.', ')']) && (rest.len() == 1 || rest[1..].starts_with(' ')) {
+            return Some((true, indent + digits + 2));
+        }
+    }
+
+    None
+}
+
+fn starts_block(line: &str) -> bool {
+    is_fence(line)
+        || is_horizontal_rule(line)
+        || heading(line).is_some()
+        || is_quote(line)
+        || list_marker(line).is_some()
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<RichtextBlock> {
+    let mut blocks = vec![];
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+
+        if is_blank(line) {
+            i += 1;
+        } else if is_fence(line) {
+            let mut code = vec![];
+            i += 1;
+
+            while i < lines.len() && !is_fence(lines[i]) {
+                code.push(CodeLine {
+                    text: lines[i].to_owned(),
+                });
+                i += 1;
+            }
+
+            // Skip the closing fence.
+            i += 1;
+            blocks.push(RichtextBlock::CodeBlock { lines: code });
+        } else if indentation(line) >= 4 {
+            let mut code = vec![];
+
+            while i < lines.len() && (indentation(lines[i]) >= 4 || is_blank(lines[i])) {
+                code.push(CodeLine {
+                    text: strip_indent(lines[i], 4).to_owned(),
+                });
+                i += 1;
+            }
+
+            while code.last().is_some_and(|line| line.text.trim().is_empty()) {
+                code.pop();
+            }
+
+            blocks.push(RichtextBlock::CodeBlock { lines: code });
+        } else if is_horizontal_rule(line) {
+            blocks.push(RichtextBlock::HorizontalRule);
+            i += 1;
+        } else if let Some((level, text)) = heading(line) {
+            blocks.push(RichtextBlock::Heading {
+                level,
+                children: spans_from_markdown(text),
+            });
+            i += 1;
+        } else if is_quote(line) {
+            let mut quote = vec![];
+
+            while i < lines.len() && is_quote(lines[i]) {
+                let content = &lines[i].trim_start()[1..];
+                quote.push(content.strip_prefix(' ').unwrap_or(content));
+                i += 1;
+            }
+
+            blocks.push(RichtextBlock::Blockquote {
+                children: parse_blocks(&quote),
+            });
+        } else if let Some((ordered, _)) = list_marker(line) {
+            let mut items = vec![];
+
+            while let Some((item_ordered, content_indent)) =
+                lines.get(i).and_then(|l| list_marker(l))
+            {
+                if item_ordered != ordered {
+                    break;
+                }
+
+                // Drop the marker and the space following it.
+                let marker_len = content_indent - indentation(lines[i]);
+                let first = lines[i].trim_start().get(marker_len..).unwrap_or("");
+                let mut content = vec![first];
+                i += 1;
+
+                while i < lines.len() {
+                    if is_blank(lines[i]) {
+                        // A blank line only continues the item if indented content follows.
+                        let next = lines[i..].iter().position(|line| !is_blank(line));
+
+                        match next {
+                            Some(offset) if indentation(lines[i + offset]) >= content_indent => {
+                                content.extend(lines[i..i + offset].iter().map(|_| ""));
+                                i += offset;
+                            }
+                            _ => break,
+                        }
+                    } else if indentation(lines[i]) >= content_indent {
+                        content.push(strip_indent(lines[i], content_indent));
+                        i += 1;
+                    } else if !starts_block(lines[i]) {
+                        // Lazy continuation of the item's paragraph.
+                        content.push(lines[i].trim_start());
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+
+                items.push(ListItem {
+                    children: parse_blocks(&content),
+                });
+
+                // Items may be separated by blank lines.
+                let next = lines[i..].iter().position(|line| !is_blank(line));
+
+                match next {
+                    Some(offset) if list_marker(lines[i + offset]).is_some() => i += offset,
+                    _ => break,
+                }
+            }
+
+            blocks.push(RichtextBlock::List { ordered, items });
+        } else {
+            let mut paragraph = String::new();
+
+            while i < lines.len()
+                && !is_blank(lines[i])
+                && (paragraph.is_empty() || !starts_block(lines[i]))
+            {
+                let line = lines[i];
+
+                if !paragraph.is_empty() {
+                    paragraph.push(if paragraph.ends_with("  ") { '\n' } else { ' ' });
+                }
+
+                paragraph.push_str(line.trim());
+
+                if line.ends_with("  ") {
+                    paragraph.push_str("  ");
+                }
+
+                i += 1;
+            }
+
+            let paragraph = paragraph.replace("  \n", "\n");
+
+            blocks.push(RichtextBlock::Paragraph {
+                children: spans_from_markdown(paragraph.trim_end()),
+            });
+        }
+    }
+
+    blocks
+}
+
+struct InlineParser {
+    chars: Vec<char>,
+    pos: usize,
+    spans: Vec<RichtextSpan>,
+    text: String,
+    text_formats: Vec<TextFormat>,
+    format: TextFormat,
+}
+
+impl InlineParser {
+    fn new(markdown: &str) -> InlineParser {
+        InlineParser {
+            chars: markdown.chars().collect(),
+            pos: 0,
+            spans: vec![],
+            text: String::new(),
+            text_formats: vec![],
+            format: TextFormat::NONE,
+        }
+    }
+
+    fn parse(mut self) -> Vec<RichtextSpan> {
+        while self.pos < self.chars.len() {
+            let c = self.chars[self.pos];
+
+            if c == '\\' && self.peek(1).is_some_and(|c| c.is_ascii_punctuation()) {
+                self.push_char(self.chars[self.pos + 1], self.format);
+                self.pos += 2;
+            } else if c == '\n' {
+                self.flush();
+                self.spans.push(RichtextSpan::LineBreak);
+                self.pos += 1;
+            } else if c == '`' {
+                self.code();
+            } else if self.starts_with(">!", self.pos) && self.find("!<", self.pos + 2).is_some() {
+                let end = self.find("!<", self.pos + 2).unwrap();
+                let inner: String = self.chars[self.pos + 2..end].iter().collect();
+
+                self.flush();
+                self.spans.push(RichtextSpan::Spoiler {
+                    children: spans_from_markdown(&inner),
+                });
+                self.pos = end + 2;
+            } else if self.starts_with("**", self.pos) {
+                self.toggle("**", TextFormat::BOLD);
+            } else if self.starts_with("~~", self.pos) {
+                self.toggle("~~", TextFormat::STRIKETHROUGH);
+            } else if c == '*' {
+                self.toggle("*", TextFormat::ITALIC);
+            } else if c == '_' && self.is_underscore_marker() {
+                self.toggle("_", TextFormat::ITALIC);
+            } else if c == '^' {
+                self.superscript();
+            } else if c == '[' && self.link() {
+                // Link was pushed.
+            } else if self.at_word_start() && self.mention() {
+                // Mention was pushed.
+            } else {
+                self.push_char(c, self.format);
+                self.pos += 1;
+            }
+        }
+
+        self.flush();
+        self.spans
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn starts_with(&self, pattern: &str, at: usize) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        self.chars.get(at..at + pattern.len()) == Some(&pattern[..])
+    }
+
+    fn find(&self, pattern: &str, from: usize) -> Option<usize> {
+        (from..self.chars.len()).find(|i| self.starts_with(pattern, *i))
+    }
+
+    fn at_word_start(&self) -> bool {
+        self.pos == 0 || !self.chars[self.pos - 1].is_alphanumeric()
+    }
+
+    /// Underscores inside words, like `snake_case`, are not formatting.
+    fn is_underscore_marker(&self) -> bool {
+        let before = self.pos.checked_sub(1).map(|i| self.chars[i]);
+        let after = self.peek(1);
+
+        !(before.is_some_and(char::is_alphanumeric) && after.is_some_and(char::is_alphanumeric))
+    }
+
+    fn push_char(&mut self, c: char, format: TextFormat) {
+        self.text.push(c);
+        self.text_formats.push(format);
+    }
+
+    /// Open or close a formatting marker. An opening marker without a closing one is text.
+    fn toggle(&mut self, marker: &str, format: TextFormat) {
+        let len = marker.chars().count();
+
+        if self.format.contains(format) {
+            self.format = self.format.without(format);
+            self.pos += len;
+        } else if self.find(marker, self.pos + len).is_some() {
+            self.format = self.format | format;
+            self.pos += len;
+        } else {
+            for c in marker.chars() {
+                self.push_char(c, self.format);
+            }
+            self.pos += len;
+        }
+    }
+
+    /// A code span ends at the next backtick run as long as the one it starts with.
+    fn code(&mut self) {
+        let fence = self.chars[self.pos..]
+            .iter()
+            .take_while(|c| **c == '`')
+            .count();
+        let start = self.pos + fence;
+        let mut end = start;
+
+        while end < self.chars.len() {
+            let run = self.chars[end..].iter().take_while(|c| **c == '`').count();
+
+            if run == fence {
+                break;
+            }
+
+            end += run.max(1);
+        }
+
+        if end >= self.chars.len() {
+            for _ in 0..fence {
+                self.push_char('`', self.format);
+            }
+            self.pos = start;
+            return;
+        }
+
+        let mut code = &self.chars[start..end];
+
+        // Strip the space that separates backticks in the code from the fence.
+        if code.len() >= 2
+            && code[0] == ' '
+            && code[code.len() - 1] == ' '
+            && code.iter().any(|c| *c != ' ')
+        {
+            code = &code[1..code.len() - 1];
+        }
+
+        let code: Vec<char> = code.to_vec();
+
+        for c in code {
+            self.push_char(c, self.format | TextFormat::CODE);
+        }
+
+        self.pos = end + fence;
+    }
+
+    fn superscript(&mut self) {
+        let format = self.format | TextFormat::SUPERSCRIPT;
+
+        if self.peek(1) == Some('(') {
+            if let Some(end) = self.find(")", self.pos + 2) {
+                for i in self.pos + 2..end {
+                    self.push_char(self.chars[i], format);
+                }
+                self.pos = end + 1;
+                return;
+            }
+        }
+
+        let mut end = self.pos + 1;
+
+        while end < self.chars.len() && !self.chars[end].is_whitespace() {
+            end += 1;
+        }
+
+        if end == self.pos + 1 {
+            self.push_char('^', self.format);
+        } else {
+            for i in self.pos + 1..end {
+                self.push_char(self.chars[i], format);
+            }
+        }
+
+        self.pos = end;
+    }
+
+    fn link(&mut self) -> bool {
+        let text_end = match self.find("](", self.pos + 1) {
+            Some(end) => end,
+            None => return false,
+        };
+
+        let url_end = match self.find(")", text_end + 2) {
+            Some(end) => end,
+            None => return false,
+        };
+
+        let text: String = self.chars[self.pos + 1..text_end].iter().collect();
+        let url: String = self.chars[text_end + 2..url_end].iter().collect();
+        let text = unescape(&text);
+        let formats = if self.format.is_empty() || text.is_empty() {
+            vec![]
+        } else {
+            vec![FormatRange {
+                format: self.format,
+                start: 0,
+                length: text.chars().count(),
+            }]
+        };
+
+        self.flush();
+        self.spans.push(RichtextSpan::Link {
+            text,
+            url: url.trim().to_owned(),
+            formats,
+        });
+        self.pos = url_end + 1;
+
+        true
+    }
+
+    fn mention(&mut self) -> bool {
+        let start = if self.chars[self.pos] == '/' {
+            self.pos + 1
+        } else {
+            self.pos
+        };
+
+        let is_user = self.starts_with("u/", start);
+
+        if !is_user && !self.starts_with("r/", start) {
+            return false;
+        }
+
+        let name_start = start + 2;
+        let mut name_end = name_start;
+
+        while name_end < self.chars.len()
+            && (self.chars[name_end].is_ascii_alphanumeric()
+                || self.chars[name_end] == '_'
+                || self.chars[name_end] == '-')
+        {
+            name_end += 1;
+        }
+
+        if name_end == name_start {
+            return false;
+        }
+
+        let name: String = self.chars[name_start..name_end].iter().collect();
+
+        self.flush();
+        self.spans.push(if is_user {
+            RichtextSpan::UserMention { name }
+        } else {
+            RichtextSpan::SubredditMention { name }
+        });
+        self.pos = name_end;
+
+        true
+    }
+
+    /// Push the collected text as a span, merging equally formatted characters into ranges.
+    fn flush(&mut self) {
+        if self.text.is_empty() {
+            return;
+        }
+
+        let mut formats = vec![];
+        let mut start = 0;
+
+        while start < self.text_formats.len() {
+            let format = self.text_formats[start];
+            let mut end = start;
+
+            while end < self.text_formats.len() && self.text_formats[end] == format {
+                end += 1;
+            }
+
+            if !format.is_empty() {
+                formats.push(FormatRange {
+                    format,
+                    start,
+                    length: end - start,
+                });
+            }
+
+            start = end;
+        }
+
+        self.spans.push(RichtextSpan::Text {
+            text: std::mem::take(&mut self.text),
+            formats,
+        });
+        self.text_formats.clear();
+    }
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek().is_some_and(|c| c.is_ascii_punctuation()) {
+            continue;
+        }
+        unescaped.push(c);
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spans_from_markdown, spans_to_markdown};
+    use crate::models::richtext::{
+        RichtextBlock, RichtextBuilder, RichtextDocument, SpanBuilder, TextFormat,
+    };
+
+    #[test]
+    fn test_inline_from_markdown() {
+        let spans = spans_from_markdown(
+            "Hi u/spez, **bold *both*** `a*b` ^(up) snake_case [docs](https://docs.rs) >!secret!<",
+        );
+
+        assert_eq!(
+            spans,
+            SpanBuilder::new()
+                .text("Hi ")
+                .user("spez")
+                .text(", ")
+                .bold("bold ")
+                .formatted("both", TextFormat::BOLD | TextFormat::ITALIC)
+                .text(" ")
+                .code("a*b")
+                .text(" ")
+                .superscript("up")
+                .text(" snake_case ")
+                .link("docs", "https://docs.rs")
+                .text(" ")
+                .spoiler(SpanBuilder::new().text("secret"))
+                .build()
+        );
+    }
+
+    #[test]
+    fn test_unclosed_marker_is_text() {
+        let spans = spans_from_markdown("2 * 3 = 6");
+        assert_eq!(spans, SpanBuilder::new().text("2 * 3 = 6").build());
+        assert_eq!(spans_to_markdown(&spans), "2 \\* 3 = 6");
+    }
+
+    #[test]
+    fn test_blocks_from_markdown() {
+        let markdown = "# Title\n\nSome *text*\nwrapped  \nbroken\n\n- one\n- two\n\n  still two\n\n1. first\n\n> quoted\n\n    let x = 1;\n\n---";
+        let document = RichtextDocument::from_markdown(markdown);
+
+        assert_eq!(
+            document,
+            RichtextBuilder::new()
+                .heading(1, SpanBuilder::new().text("Title"))
+                .paragraph(
+                    SpanBuilder::new()
+                        .text("Some ")
+                        .italic("text")
+                        .text(" wrapped")
+                        .line_break()
+                        .text("broken")
+                )
+                .block(RichtextBlock::List {
+                    ordered: false,
+                    items: vec![
+                        crate::models::richtext::ListItem {
+                            children: vec![RichtextBlock::Paragraph {
+                                children: SpanBuilder::new().text("one").build()
+                            }]
+                        },
+                        crate::models::richtext::ListItem {
+                            children: vec![
+                                RichtextBlock::Paragraph {
+                                    children: SpanBuilder::new().text("two").build()
+                                },
+                                RichtextBlock::Paragraph {
+                                    children: SpanBuilder::new().text("still two").build()
+                                }
+                            ]
+                        }
+                    ]
+                })
+                .list(true, vec![SpanBuilder::new().text("first")])
+                .blockquote(RichtextBuilder::new().paragraph(SpanBuilder::new().text("quoted")))
+                .code_block("let x = 1;")
+                .horizontal_rule()
+                .build()
+        );
+    }
+
+    #[test]
+    fn test_markdown_round_trip() {
+        let document = RichtextBuilder::new()
+            .heading(2, SpanBuilder::new().text("Rules"))
+            .paragraph(
+                SpanBuilder::new()
+                    .text("Be ")
+                    .bold("nice")
+                    .text(" to ")
+                    .subreddit("rust")
+                    .line_break()
+                    .strikethrough("or else"),
+            )
+            .list(
+                true,
+                vec![
+                    SpanBuilder::new().text("no spam"),
+                    SpanBuilder::new().code("unsafe").text(" is fine"),
+                ],
+            )
+            .blockquote(RichtextBuilder::new().paragraph(SpanBuilder::new().italic("quote")))
+            .code_block("fn main() {\n    println!(\"*hi*\");\n}")
+            .horizontal_rule()
+            .paragraph(SpanBuilder::new().text("1. not a list"))
+            .paragraph(SpanBuilder::new().text("2) nor this"))
+            .paragraph(
+                SpanBuilder::new()
+                    .text("- not a list")
+                    .line_break()
+                    .text("+ nor this")
+                    .line_break()
+                    .text("# not a heading"),
+            )
+            .paragraph(SpanBuilder::new().text("u/spez and /r/rust are not mentions"))
+            .paragraph(SpanBuilder::new().code("a `b` c").text(" and ").code("`"))
+            .build();
+
+        let markdown = document.to_markdown();
+        assert!(markdown.contains("1\\. not a list\n\n2\\) nor this"));
+        assert!(markdown.contains("u\\/spez and /r\\/rust"));
+        assert!(markdown.contains("``a `b` c`` and `` ` ``"));
+        assert_eq!(RichtextDocument::from_markdown(&markdown), document);
+    }
+}
@@ -0,0 +1,526 @@
+//! # Richtext
+//! Reddit's richtext format, used by richtext flairs and richtext posts.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::richtext::{RichtextBuilder, RichtextDocument, SpanBuilder};
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let me = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .login()
+//!         .await
+//!         .unwrap();
+//!
+//!     let post = RichtextBuilder::new()
+//!         .heading(1, SpanBuilder::new().text("Weekly thread"))
+//!         .paragraph(
+//!             SpanBuilder::new()
+//!                 .text("Read the ")
+//!                 .link("rules", "https://www.reddit.com/r/rust/about/rules")
+//!                 .text(" before posting, ")
+//!                 .bold("please"),
+//!         )
+//!         .code_block("fn main() {\n    println!(\"Hello\");\n}")
+//!         .build();
+//!
+//!     me.submit_richtext("Weekly thread", &post, "rust").await;
+//!
+//!     // Or convert from and to Markdown.
+//!     let post = RichtextDocument::from_markdown("# Weekly thread\n\nRead the **rules**.");
+//!     println!("{}", post.to_markdown());
+//! }
+//! ```
+
+pub mod markdown;
+
+use std::ops::BitOr;
+
+use serde::{Deserialize, Serialize};
+
+/// Formatting applied to a range of text, as a set of flags.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TextFormat(pub u32);
+
+impl TextFormat {
+    /// No formatting
+    pub const NONE: TextFormat = TextFormat(0);
+    /// Bold
+    pub const BOLD: TextFormat = TextFormat(1);
+    /// Italic
+    pub const ITALIC: TextFormat = TextFormat(2);
+    /// Strikethrough
+    pub const STRIKETHROUGH: TextFormat = TextFormat(8);
+    /// Superscript
+    pub const SUPERSCRIPT: TextFormat = TextFormat(32);
+    /// Inline code
+    pub const CODE: TextFormat = TextFormat(64);
+
+    /// Returns `true` if all flags of `other` are set.
+    pub fn contains(self, other: TextFormat) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no flags are set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns these flags without the flags of `other`.
+    pub fn without(self, other: TextFormat) -> TextFormat {
+        TextFormat(self.0 & !other.0)
+    }
+}
+
+impl BitOr for TextFormat {
+    type Output = TextFormat;
+
+    fn bitor(self, other: TextFormat) -> TextFormat {
+        TextFormat(self.0 | other.0)
+    }
+}
+
+/// Formatting of a range of characters of a text span.
+/// Reddit sends these as `[format, start, length]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "(u32, usize, usize)", into = "(u32, usize, usize)")]
+pub struct FormatRange {
+    /// The formatting applied
+    pub format: TextFormat,
+    /// The first formatted character
+    pub start: usize,
+    /// The number of formatted characters
+    pub length: usize,
+}
+
+impl From<(u32, usize, usize)> for FormatRange {
+    fn from((format, start, length): (u32, usize, usize)) -> Self {
+        FormatRange {
+            format: TextFormat(format),
+            start,
+            length,
+        }
+    }
+}
+
+impl From<FormatRange> for (u32, usize, usize) {
+    fn from(range: FormatRange) -> Self {
+        (range.format.0, range.start, range.length)
+    }
+}
+
+/// An inline element of richtext. Richtext flairs are a list of these.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum RichtextSpan {
+    /// Text, with optional formatting
+    #[serde(rename = "text")]
+    Text {
+        /// Text
+        #[serde(rename = "t")]
+        text: String,
+        /// Formatted ranges of the text
+        #[serde(rename = "f", default, skip_serializing_if = "Vec::is_empty")]
+        formats: Vec<FormatRange>,
+    },
+    /// A link
+    #[serde(rename = "link")]
+    Link {
+        /// Link text
+        #[serde(rename = "t")]
+        text: String,
+        /// Url
+        #[serde(rename = "u")]
+        url: String,
+        /// Formatted ranges of the link text
+        #[serde(rename = "f", default, skip_serializing_if = "Vec::is_empty")]
+        formats: Vec<FormatRange>,
+    },
+    /// A mention of a user, e.g. `u/spez`
+    #[serde(rename = "u/")]
+    UserMention {
+        /// The name of the user, without `u/`
+        #[serde(rename = "t")]
+        name: String,
+    },
+    /// A mention of a subreddit, e.g. `r/rust`
+    #[serde(rename = "r/")]
+    SubredditMention {
+        /// The name of the subreddit, without `r/`
+        #[serde(rename = "t")]
+        name: String,
+    },
+    /// A custom emoji
+    #[serde(rename = "emoji")]
+    Emoji {
+        /// The name of the emoji, e.g. `:ferris:`
+        #[serde(rename = "a")]
+        name: String,
+        /// The url of the emoji image
+        #[serde(rename = "u", default, skip_serializing_if = "String::is_empty")]
+        url: String,
+    },
+    /// Spoiler text
+    #[serde(rename = "spoilertext")]
+    Spoiler {
+        /// Hidden content
+        #[serde(rename = "c")]
+        children: Vec<RichtextSpan>,
+    },
+    /// A line break
+    #[serde(rename = "br")]
+    LineBreak,
+    /// An element roux does not know about yet
+    #[serde(other, rename = "unknown")]
+    Unknown,
+}
+
+/// A block element of a richtext document.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum RichtextBlock {
+    /// A paragraph
+    #[serde(rename = "par")]
+    Paragraph {
+        /// Content
+        #[serde(rename = "c")]
+        children: Vec<RichtextSpan>,
+    },
+    /// A heading
+    #[serde(rename = "h")]
+    Heading {
+        /// Level, from 1 to 6
+        #[serde(rename = "l")]
+        level: u8,
+        /// Content
+        #[serde(rename = "c")]
+        children: Vec<RichtextSpan>,
+    },
+    /// An ordered or unordered list
+    #[serde(rename = "list")]
+    List {
+        /// Whether the list is numbered
+        #[serde(rename = "o")]
+        ordered: bool,
+        /// Items
+        #[serde(rename = "c")]
+        items: Vec<ListItem>,
+    },
+    /// A code block
+    #[serde(rename = "code")]
+    CodeBlock {
+        /// Lines of code
+        #[serde(rename = "c")]
+        lines: Vec<CodeLine>,
+    },
+    /// A quote
+    #[serde(rename = "blockquote")]
+    Blockquote {
+        /// Content
+        #[serde(rename = "c")]
+        children: Vec<RichtextBlock>,
+    },
+    /// A horizontal rule
+    #[serde(rename = "hr")]
+    HorizontalRule,
+    /// An element roux does not know about yet
+    #[serde(other, rename = "unknown")]
+    Unknown,
+}
+
+/// An item of a `RichtextBlock::List`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "e", rename = "li")]
+pub struct ListItem {
+    /// Content
+    #[serde(rename = "c")]
+    pub children: Vec<RichtextBlock>,
+}
+
+/// A line of a `RichtextBlock::CodeBlock`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "e", rename = "raw")]
+pub struct CodeLine {
+    /// Code
+    #[serde(rename = "t")]
+    pub text: String,
+}
+
+/// A richtext document, as used by richtext posts.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RichtextDocument {
+    /// Blocks of the document
+    pub document: Vec<RichtextBlock>,
+}
+
+/// Builds a `RichtextDocument` block by block.
+#[derive(Clone, Debug, Default)]
+pub struct RichtextBuilder {
+    blocks: Vec<RichtextBlock>,
+}
+
+impl RichtextBuilder {
+    /// Create a new `RichtextBuilder` instance.
+    pub fn new() -> RichtextBuilder {
+        RichtextBuilder::default()
+    }
+
+    /// Add a block.
+    pub fn block(mut self, block: RichtextBlock) -> RichtextBuilder {
+        self.blocks.push(block);
+        self
+    }
+
+    /// Add a paragraph.
+    pub fn paragraph(self, spans: SpanBuilder) -> RichtextBuilder {
+        self.block(RichtextBlock::Paragraph {
+            children: spans.build(),
+        })
+    }
+
+    /// Add a heading. The level is clamped between 1 and 6.
+    pub fn heading(self, level: u8, spans: SpanBuilder) -> RichtextBuilder {
+        self.block(RichtextBlock::Heading {
+            level: level.clamp(1, 6),
+            children: spans.build(),
+        })
+    }
+
+    /// Add a list with one paragraph per item.
+    pub fn list(self, ordered: bool, items: Vec<SpanBuilder>) -> RichtextBuilder {
+        self.block(RichtextBlock::List {
+            ordered,
+            items: items
+                .into_iter()
+                .map(|spans| ListItem {
+                    children: vec![RichtextBlock::Paragraph {
+                        children: spans.build(),
+                    }],
+                })
+                .collect(),
+        })
+    }
+
+    /// Add a code block.
+    pub fn code_block(self, code: &str) -> RichtextBuilder {
+        self.block(RichtextBlock::CodeBlock {
+            lines: code
+                .lines()
+                .map(|line| CodeLine {
+                    text: line.to_owned(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Add a quote.
+    pub fn blockquote(self, quote: RichtextBuilder) -> RichtextBuilder {
+        self.block(RichtextBlock::Blockquote {
+            children: quote.blocks,
+        })
+    }
+
+    /// Add a horizontal rule.
+    pub fn horizontal_rule(self) -> RichtextBuilder {
+        self.block(RichtextBlock::HorizontalRule)
+    }
+
+    /// Build the document.
+    pub fn build(self) -> RichtextDocument {
+        RichtextDocument {
+            document: self.blocks,
+        }
+    }
+}
+
+/// Builds a list of `RichtextSpan`, e.g. the content of a paragraph or a richtext flair.
+/// Consecutive text is merged into a single span with formatted ranges.
+#[derive(Clone, Debug, Default)]
+pub struct SpanBuilder {
+    spans: Vec<RichtextSpan>,
+}
+
+impl SpanBuilder {
+    /// Create a new `SpanBuilder` instance.
+    pub fn new() -> SpanBuilder {
+        SpanBuilder::default()
+    }
+
+    /// Add a span.
+    pub fn span(mut self, span: RichtextSpan) -> SpanBuilder {
+        self.spans.push(span);
+        self
+    }
+
+    /// Add text with formatting.
+    pub fn formatted(mut self, text: &str, format: TextFormat) -> SpanBuilder {
+        if text.is_empty() {
+            return self;
+        }
+
+        if let Some(RichtextSpan::Text {
+            text: current,
+            formats,
+        }) = self.spans.last_mut()
+        {
+            if !format.is_empty() {
+                formats.push(FormatRange {
+                    format,
+                    start: current.chars().count(),
+                    length: text.chars().count(),
+                });
+            }
+
+            current.push_str(text);
+            return self;
+        }
+
+        let formats = if format.is_empty() {
+            vec![]
+        } else {
+            vec![FormatRange {
+                format,
+                start: 0,
+                length: text.chars().count(),
+            }]
+        };
+
+        self.span(RichtextSpan::Text {
+            text: text.to_owned(),
+            formats,
+        })
+    }
+
+    /// Add plain text.
+    pub fn text(self, text: &str) -> SpanBuilder {
+        self.formatted(text, TextFormat::NONE)
+    }
+
+    /// Add bold text.
+    pub fn bold(self, text: &str) -> SpanBuilder {
+        self.formatted(text, TextFormat::BOLD)
+    }
+
+    /// Add italic text.
+    pub fn italic(self, text: &str) -> SpanBuilder {
+        self.formatted(text, TextFormat::ITALIC)
+    }
+
+    /// Add strikethrough text.
+    pub fn strikethrough(self, text: &str) -> SpanBuilder {
+        self.formatted(text, TextFormat::STRIKETHROUGH)
+    }
+
+    /// Add superscript text.
+    pub fn superscript(self, text: &str) -> SpanBuilder {
+        self.formatted(text, TextFormat::SUPERSCRIPT)
+    }
+
+    /// Add inline code.
+    pub fn code(self, text: &str) -> SpanBuilder {
+        self.formatted(text, TextFormat::CODE)
+    }
+
+    /// Add a link.
+    pub fn link(self, text: &str, url: &str) -> SpanBuilder {
+        self.span(RichtextSpan::Link {
+            text: text.to_owned(),
+            url: url.to_owned(),
+            formats: vec![],
+        })
+    }
+
+    /// Add a user mention.
+    pub fn user(self, name: &str) -> SpanBuilder {
+        self.span(RichtextSpan::UserMention {
+            name: name.to_owned(),
+        })
+    }
+
+    /// Add a subreddit mention.
+    pub fn subreddit(self, name: &str) -> SpanBuilder {
+        self.span(RichtextSpan::SubredditMention {
+            name: name.to_owned(),
+        })
+    }
+
+    /// Add a custom emoji, e.g. `:ferris:`.
+    pub fn emoji(self, name: &str, url: &str) -> SpanBuilder {
+        self.span(RichtextSpan::Emoji {
+            name: name.to_owned(),
+            url: url.to_owned(),
+        })
+    }
+
+    /// Add spoiler text.
+    pub fn spoiler(self, spans: SpanBuilder) -> SpanBuilder {
+        self.span(RichtextSpan::Spoiler {
+            children: spans.build(),
+        })
+    }
+
+    /// Add a line break.
+    pub fn line_break(self) -> SpanBuilder {
+        self.span(RichtextSpan::LineBreak)
+    }
+
+    /// Build the spans.
+    pub fn build(self) -> Vec<RichtextSpan> {
+        self.spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RichtextBuilder, RichtextDocument, RichtextSpan, SpanBuilder};
+
+    #[test]
+    fn test_serde_document() {
+        let document = RichtextBuilder::new()
+            .paragraph(SpanBuilder::new().text("Hello ").bold("world"))
+            .list(false, vec![SpanBuilder::new().user("spez")])
+            .code_block("let x = 1;")
+            .build();
+
+        let json = serde_json::to_value(&document).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"document": [
+                {"e": "par", "c": [{"e": "text", "t": "Hello world", "f": [[1, 6, 5]]}]},
+                {"e": "list", "o": false, "c": [
+                    {"e": "li", "c": [{"e": "par", "c": [{"e": "u/", "t": "spez"}]}]}
+                ]},
+                {"e": "code", "c": [{"e": "raw", "t": "let x = 1;"}]}
+            ]})
+        );
+
+        let parsed: RichtextDocument = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, document);
+    }
+
+    #[test]
+    fn test_deserialize_flair() {
+        let flair: Vec<RichtextSpan> = serde_json::from_str(
+            r#"[{"e": "emoji", "a": ":ferris:", "u": "https://emoji.redditmedia.com/x.png"},
+                {"e": "text", "t": " Rustacean"},
+                {"e": "sparkles"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            flair,
+            SpanBuilder::new()
+                .emoji(":ferris:", "https://emoji.redditmedia.com/x.png")
+                .text(" Rustacean")
+                .span(RichtextSpan::Unknown)
+                .build()
+        );
+    }
+}
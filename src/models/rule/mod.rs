@@ -0,0 +1,221 @@
+//! # Rules
+//! Subreddit rules, removal reasons and post requirements.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let subreddit = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .subreddit("rust")
+//!         .await
+//!         .unwrap();
+//!
+//!     let rules = subreddit.rules().await.unwrap();
+//!
+//!     // Check a post before submitting it.
+//!     let requirements = subreddit.post_requirements().await.unwrap();
+//!     let violations = requirements.validate("Title", Some("Body"), None, false);
+//!     assert!(violations.is_empty());
+//! }
+//! ```
+
+pub mod response;
+
+use crate::models::rule::response::PostRequirements;
+
+/// A way a post does not meet the `PostRequirements` of a subreddit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PostViolation {
+    /// The title is shorter than the minimum length.
+    TitleTooShort(usize),
+    /// The title is longer than the maximum length.
+    TitleTooLong(usize),
+    /// The title contains none of the required strings.
+    TitleMissingRequired,
+    /// The title contains a blacklisted string.
+    TitleBlacklisted(String),
+    /// The subreddit requires a body.
+    BodyRequired,
+    /// The subreddit does not allow a body.
+    BodyNotAllowed,
+    /// The body is shorter than the minimum length.
+    BodyTooShort(usize),
+    /// The body is longer than the maximum length.
+    BodyTooLong(usize),
+    /// The body contains none of the required strings.
+    BodyMissingRequired,
+    /// The body contains a blacklisted string.
+    BodyBlacklisted(String),
+    /// The link's domain is not allowed.
+    DomainNotAllowed(String),
+    /// The subreddit requires a flair.
+    FlairRequired,
+}
+
+impl PostRequirements {
+    /// Check a post against these requirements. Returns every requirement the post does
+    /// not meet, so an empty list means the post can be submitted.
+    /// String matching is case insensitive. Title and body regexes are not checked.
+    pub fn validate(
+        &self,
+        title: &str,
+        body: Option<&str>,
+        link: Option<&str>,
+        flaired: bool,
+    ) -> Vec<PostViolation> {
+        let mut violations = vec![];
+        let title_len = title.chars().count();
+
+        if let Some(min) = self.title_text_min_length {
+            if title_len < min {
+                violations.push(PostViolation::TitleTooShort(min));
+            }
+        }
+
+        if let Some(max) = self.title_text_max_length {
+            if title_len > max {
+                violations.push(PostViolation::TitleTooLong(max));
+            }
+        }
+
+        if !contains_any(title, &self.title_required_strings) {
+            violations.push(PostViolation::TitleMissingRequired);
+        }
+
+        if let Some(blacklisted) = find_any(title, &self.title_blacklisted_strings) {
+            violations.push(PostViolation::TitleBlacklisted(blacklisted));
+        }
+
+        let body = body.filter(|body| !body.is_empty());
+
+        match (self.body_restriction_policy.as_deref(), body) {
+            (Some("required"), None) => violations.push(PostViolation::BodyRequired),
+            (Some("notAllowed"), Some(_)) => violations.push(PostViolation::BodyNotAllowed),
+            _ => {}
+        }
+
+        if let Some(body) = body {
+            let body_len = body.chars().count();
+
+            if let Some(min) = self.body_text_min_length {
+                if body_len < min {
+                    violations.push(PostViolation::BodyTooShort(min));
+                }
+            }
+
+            if let Some(max) = self.body_text_max_length {
+                if body_len > max {
+                    violations.push(PostViolation::BodyTooLong(max));
+                }
+            }
+
+            if !contains_any(body, &self.body_required_strings) {
+                violations.push(PostViolation::BodyMissingRequired);
+            }
+
+            if let Some(blacklisted) = find_any(body, &self.body_blacklisted_strings) {
+                violations.push(PostViolation::BodyBlacklisted(blacklisted));
+            }
+        }
+
+        if let Some(domain) = link.and_then(link_domain) {
+            let allowed = match self.link_restriction_policy.as_deref() {
+                Some("whitelist") => matches_domain(&domain, &self.domain_whitelist),
+                Some("blacklist") => !matches_domain(&domain, &self.domain_blacklist),
+                _ => true,
+            };
+
+            if !allowed {
+                violations.push(PostViolation::DomainNotAllowed(domain));
+            }
+        }
+
+        if self.is_flair_required && !flaired {
+            violations.push(PostViolation::FlairRequired);
+        }
+
+        violations
+    }
+}
+
+/// Returns `true` if `text` contains one of `needles`, or if there are no needles.
+fn contains_any(text: &str, needles: &[String]) -> bool {
+    needles.is_empty() || find_any(text, needles).is_some()
+}
+
+fn find_any(text: &str, needles: &[String]) -> Option<String> {
+    let text = text.to_lowercase();
+
+    needles
+        .iter()
+        .find(|needle| text.contains(&needle.to_lowercase()))
+        .cloned()
+}
+
+fn link_domain(link: &str) -> Option<String> {
+    let without_scheme = link.split_once("://").map_or(link, |(_, rest)| rest);
+    let host = without_scheme.split(&['/', '?', '#'][..]).next()?;
+    let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    let host = host.split(':').next()?;
+
+    if host.is_empty() {
+        None
+    } else {
+        Some(host.to_lowercase())
+    }
+}
+
+fn matches_domain(domain: &str, list: &[String]) -> bool {
+    list.iter().any(|entry| {
+        let entry = entry.to_lowercase();
+        domain == entry || domain.ends_with(&format!(".{}", entry))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::response::PostRequirements;
+    use super::PostViolation;
+
+    #[test]
+    fn test_validate_post() {
+        let requirements = PostRequirements {
+            title_text_min_length: Some(10),
+            title_required_strings: vec![String::from("[Help]"), String::from("[Meta]")],
+            title_blacklisted_strings: vec![String::from("urgent")],
+            body_restriction_policy: Some(String::from("required")),
+            link_restriction_policy: Some(String::from("whitelist")),
+            domain_whitelist: vec![String::from("github.com")],
+            is_flair_required: true,
+            ..Default::default()
+        };
+
+        let violations = requirements.validate("URGENT", None, Some("https://evil.com/x"), false);
+        assert_eq!(
+            violations,
+            vec![
+                PostViolation::TitleTooShort(10),
+                PostViolation::TitleMissingRequired,
+                PostViolation::TitleBlacklisted(String::from("urgent")),
+                PostViolation::BodyRequired,
+                PostViolation::DomainNotAllowed(String::from("evil.com")),
+                PostViolation::FlairRequired,
+            ]
+        );
+
+        let violations = requirements.validate(
+            "[help] borrow checker",
+            Some("Why does this not compile?"),
+            Some("https://gist.github.com/abc"),
+            true,
+        );
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+//! # Rule Responses
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// What a rule applies to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleKind {
+    /// Submissions
+    Link,
+    /// Comments
+    Comment,
+    /// Submissions and comments
+    All,
+}
+
+impl RuleKind {
+    /// Gets the request string for the kind
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RuleKind::Link => "link",
+            RuleKind::Comment => "comment",
+            RuleKind::All => "all",
+        }
+    }
+}
+
+/// A subreddit rule.
+#[derive(Debug, Deserialize)]
+pub struct Rule {
+    /// What the rule applies to
+    pub kind: RuleKind,
+    /// The name of the rule, also used to identify it
+    pub short_name: String,
+    /// Description in **Markdown** format
+    #[serde(default)]
+    pub description: String,
+    /// Description in HTML format
+    pub description_html: Option<String>,
+    /// The reason shown when reporting, defaults to the short name
+    pub violation_reason: Option<String>,
+    /// Position of the rule, starting at 0
+    pub priority: u32,
+    /// Created (UTC)
+    pub created_utc: f64,
+}
+
+/// The rules of a subreddit.
+#[derive(Debug, Deserialize)]
+pub struct Rules {
+    /// Subreddit rules
+    pub rules: Vec<Rule>,
+    /// Reddit wide rules
+    #[serde(default)]
+    pub site_rules: Vec<String>,
+}
+
+/// A removal reason.
+#[derive(Debug, Deserialize)]
+pub struct RemovalReason {
+    /// ID
+    pub id: String,
+    /// Title
+    pub title: String,
+    /// The message sent to the author
+    pub message: String,
+}
+
+/// The removal reasons of a subreddit.
+#[derive(Debug, Deserialize)]
+pub struct RemovalReasons {
+    /// Removal reasons by ID
+    pub data: HashMap<String, RemovalReason>,
+    /// Removal reason IDs in order
+    pub order: Vec<String>,
+}
+
+impl RemovalReasons {
+    /// Get the removal reasons in order.
+    pub fn ordered(&self) -> Vec<&RemovalReason> {
+        self.order
+            .iter()
+            .filter_map(|id| self.data.get(id))
+            .collect()
+    }
+}
+
+/// The requirements submissions to a subreddit must meet.
+#[derive(Debug, Default, Deserialize)]
+pub struct PostRequirements {
+    /// Minimum title length
+    pub title_text_min_length: Option<usize>,
+    /// Maximum title length
+    pub title_text_max_length: Option<usize>,
+    /// The title must contain at least one of these
+    #[serde(default)]
+    pub title_required_strings: Vec<String>,
+    /// The title must not contain any of these
+    #[serde(default)]
+    pub title_blacklisted_strings: Vec<String>,
+    /// Regexes the title must match. Not checked by `validate`.
+    #[serde(default)]
+    pub title_regexes: Vec<String>,
+    /// Whether a body is `required`, `notAllowed` or optional (`none`)
+    pub body_restriction_policy: Option<String>,
+    /// Minimum body length
+    pub body_text_min_length: Option<usize>,
+    /// Maximum body length
+    pub body_text_max_length: Option<usize>,
+    /// The body must contain at least one of these
+    #[serde(default)]
+    pub body_required_strings: Vec<String>,
+    /// The body must not contain any of these
+    #[serde(default)]
+    pub body_blacklisted_strings: Vec<String>,
+    /// Regexes the body must match. Not checked by `validate`.
+    #[serde(default)]
+    pub body_regexes: Vec<String>,
+    /// Whether link domains are restricted to a `whitelist`, by a `blacklist`
+    /// or not at all (`none`)
+    pub link_restriction_policy: Option<String>,
+    /// Allowed link domains
+    #[serde(default)]
+    pub domain_whitelist: Vec<String>,
+    /// Forbidden link domains
+    #[serde(default)]
+    pub domain_blacklist: Vec<String>,
+    /// Days before a link may be reposted
+    pub link_repost_age: Option<u32>,
+    /// Whether submissions must be flaired
+    #[serde(default)]
+    pub is_flair_required: bool,
+    /// Minimum number of gallery items
+    pub gallery_min_items: Option<u32>,
+    /// Maximum number of gallery items
+    pub gallery_max_items: Option<u32>,
+    /// Guidelines shown when submitting
+    pub guidelines_text: Option<String>,
+}
@@ -0,0 +1,406 @@
+//! # Search
+//! Search posts, subreddits and users, site-wide or within a subreddit.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Subreddit;
+//! use roux::search::{self, SearchField, SearchQuery, SearchSort, SearchType};
+//! use roux::util::TimePeriod;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     // title:"async traits" AND NOT author:AutoModerator
+//!     let query = SearchQuery::new()
+//!         .title("async traits")
+//!         .and()
+//!         .not()
+//!         .author("AutoModerator")
+//!         .sort(SearchSort::Top)
+//!         .period(TimePeriod::ThisYear);
+//!
+//!     // Search within r/rust.
+//!     let posts = Subreddit::new("rust").search(&query, None).await;
+//!
+//!     // Search subreddits and users site-wide.
+//!     let query = SearchQuery::new()
+//!         .text("rust")
+//!         .result_types(&[SearchType::Subreddit, SearchType::User]);
+//!     let results = search::search(&query, None).await;
+//! }
+//! ```
+
+pub mod response;
+
+use crate::util::defaults::default_client;
+use crate::util::{url, FeedOption, RouxError, TimePeriod};
+
+use response::SearchResults;
+
+/// The sort of search results.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchSort {
+    /// Best match
+    Relevance,
+    /// Hot
+    Hot,
+    /// Top, use `SearchQuery::period` to set the time period
+    Top,
+    /// New
+    New,
+    /// Most comments
+    Comments,
+}
+
+impl SearchSort {
+    /// Gets the request string for the sort
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchSort::Relevance => "relevance",
+            SearchSort::Hot => "hot",
+            SearchSort::Top => "top",
+            SearchSort::New => "new",
+            SearchSort::Comments => "comments",
+        }
+    }
+}
+
+/// The type of search results.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchType {
+    /// Posts
+    Link,
+    /// Subreddits
+    Subreddit,
+    /// Users
+    User,
+}
+
+impl SearchType {
+    /// Gets the request string for the type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::Link => "link",
+            SearchType::Subreddit => "sr",
+            SearchType::User => "user",
+        }
+    }
+}
+
+/// A field of a post to search in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchField {
+    /// Title of the post
+    Title,
+    /// Author of the post
+    Author,
+    /// Link flair text of the post
+    Flair,
+    /// Body of a text post
+    SelfText,
+    /// Subreddit of the post
+    Subreddit,
+    /// Domain of a link post
+    Site,
+    /// Url of a link post
+    Url,
+}
+
+impl SearchField {
+    /// Gets the request string for the field
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchField::Title => "title",
+            SearchField::Author => "author",
+            SearchField::Flair => "flair",
+            SearchField::SelfText => "selftext",
+            SearchField::Subreddit => "subreddit",
+            SearchField::Site => "site",
+            SearchField::Url => "url",
+        }
+    }
+}
+
+/// Characters with a meaning in the search syntax.
+const SPECIAL_CHARS: &[char] = &[
+    '+', '-', '&', '|', '!', '(', ')', '{', '}', '[', ']', '^', '"', '~', '*', '?', ':', '\\', '/',
+];
+
+/// Escapes a single word so it is matched literally.
+fn escape_word(word: &str) -> String {
+    if matches!(word, "AND" | "OR" | "NOT") {
+        return format!("\"{}\"", word);
+    }
+
+    let mut escaped = String::with_capacity(word.len());
+
+    for c in word.chars() {
+        if SPECIAL_CHARS.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Quotes a phrase so it is matched literally.
+fn quote(phrase: &str) -> String {
+    format!("\"{}\"", phrase.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Escapes the value of a field, quoting it if it is more than one word.
+fn escape_value(value: &str) -> String {
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        quote(value)
+    } else {
+        escape_word(value)
+    }
+}
+
+/// Search query and options.
+///
+/// Terms are joined with spaces, which Reddit treats as `AND`.
+/// Use `and`, `or`, `not` and `group` to combine them explicitly.
+#[derive(Clone, Debug, Default)]
+pub struct SearchQuery {
+    /// The query string.
+    pub query: String,
+    /// The sort of the results.
+    pub sort: Option<SearchSort>,
+    /// The time period to search in.
+    pub period: Option<TimePeriod>,
+    /// Only search within the subreddit, when searching a subreddit.
+    pub restrict_sr: Option<bool>,
+    /// The types of results, when searching site-wide.
+    pub result_types: Vec<SearchType>,
+    /// Include NSFW results.
+    pub include_over_18: Option<bool>,
+}
+
+impl SearchQuery {
+    /// Create a new `SearchQuery` instance.
+    pub fn new() -> SearchQuery {
+        SearchQuery::default()
+    }
+
+    fn push(mut self, term: &str) -> SearchQuery {
+        if !self.query.is_empty() {
+            self.query.push(' ');
+        }
+
+        self.query.push_str(term);
+        self
+    }
+
+    /// Search for words, escaping any search syntax in them.
+    pub fn text(self, text: &str) -> SearchQuery {
+        let words = text
+            .split_whitespace()
+            .map(escape_word)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        self.push(&words)
+    }
+
+    /// Search for an exact phrase.
+    pub fn phrase(self, phrase: &str) -> SearchQuery {
+        self.push(&quote(phrase))
+    }
+
+    /// Search for a value in a field of posts.
+    pub fn field(self, field: SearchField, value: &str) -> SearchQuery {
+        self.push(&format!("{}:{}", field.as_str(), escape_value(value)))
+    }
+
+    /// Search in titles.
+    pub fn title(self, value: &str) -> SearchQuery {
+        self.field(SearchField::Title, value)
+    }
+
+    /// Search for posts by an author.
+    pub fn author(self, value: &str) -> SearchQuery {
+        self.field(SearchField::Author, value)
+    }
+
+    /// Search for posts with a link flair.
+    pub fn flair(self, value: &str) -> SearchQuery {
+        self.field(SearchField::Flair, value)
+    }
+
+    /// Search for links to a domain.
+    pub fn site(self, value: &str) -> SearchQuery {
+        self.field(SearchField::Site, value)
+    }
+
+    /// Only search text posts, or only link posts.
+    pub fn is_self(self, is_self: bool) -> SearchQuery {
+        self.push(if is_self { "self:yes" } else { "self:no" })
+    }
+
+    /// Only search NSFW posts, or only SFW posts.
+    pub fn nsfw(self, nsfw: bool) -> SearchQuery {
+        self.push(if nsfw { "nsfw:yes" } else { "nsfw:no" })
+    }
+
+    /// Require both the previous and the next term.
+    pub fn and(self) -> SearchQuery {
+        self.push("AND")
+    }
+
+    /// Require either the previous or the next term.
+    pub fn or(self) -> SearchQuery {
+        self.push("OR")
+    }
+
+    /// Exclude the next term.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> SearchQuery {
+        self.push("NOT")
+    }
+
+    /// Add the terms of another query in parentheses.
+    pub fn group(self, group: SearchQuery) -> SearchQuery {
+        self.push(&format!("({})", group.query))
+    }
+
+    /// Add terms without escaping them.
+    pub fn raw(self, terms: &str) -> SearchQuery {
+        self.push(terms)
+    }
+
+    /// Set sort param.
+    pub fn sort(mut self, sort: SearchSort) -> SearchQuery {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set t param.
+    pub fn period(mut self, period: TimePeriod) -> SearchQuery {
+        self.period = Some(period);
+        self
+    }
+
+    /// Set restrict_sr param.
+    pub fn restrict_sr(mut self, restrict_sr: bool) -> SearchQuery {
+        self.restrict_sr = Some(restrict_sr);
+        self
+    }
+
+    /// Set type param.
+    pub fn result_types(mut self, result_types: &[SearchType]) -> SearchQuery {
+        self.result_types = result_types.to_vec();
+        self
+    }
+
+    /// Set include_over_18 param.
+    pub fn include_over_18(mut self, include_over_18: bool) -> SearchQuery {
+        self.include_over_18 = Some(include_over_18);
+        self
+    }
+
+    /// Append the query params to a url built by `FeedOption::build_url`.
+    pub fn build_url(&self, url: &mut String) {
+        url.push_str(&format!("q={}&", url::encode(&self.query)));
+
+        if let Some(sort) = self.sort {
+            url.push_str(&format!("sort={}&", sort.as_str()));
+        }
+
+        if let Some(period) = self.period {
+            url.push_str(&format!("t={}&", period.get_string_for_period()));
+        }
+
+        if let Some(restrict_sr) = self.restrict_sr {
+            url.push_str(&format!("restrict_sr={}&", restrict_sr));
+        }
+
+        if !self.result_types.is_empty() {
+            let types = self
+                .result_types
+                .iter()
+                .map(SearchType::as_str)
+                .collect::<Vec<_>>()
+                .join(",");
+            url.push_str(&format!("type={}&", types));
+        }
+
+        if let Some(include_over_18) = self.include_over_18 {
+            url.push_str(&format!("include_over_18={}&", include_over_18));
+        }
+    }
+}
+
+/// Search site-wide.
+#[maybe_async::maybe_async]
+pub async fn search(
+    query: &SearchQuery,
+    options: Option<FeedOption>,
+) -> Result<SearchResults, RouxError> {
+    let url = &mut String::from("https://www.reddit.com/search.json");
+
+    options.unwrap_or_default().build_url(url);
+    query.build_url(url);
+
+    Ok(default_client()
+        .get(url.as_str())
+        .send()
+        .await?
+        .json::<SearchResults>()
+        .await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SearchField, SearchQuery, SearchSort, SearchType};
+    use crate::util::FeedOption;
+
+    #[test]
+    fn test_query_syntax() {
+        let query = SearchQuery::new()
+            .title("async traits")
+            .and()
+            .group(SearchQuery::new().flair("Help").or().is_self(true))
+            .not()
+            .author("AutoModerator")
+            .site("github.com");
+
+        assert_eq!(
+            query.query,
+            "title:\"async traits\" AND (flair:Help OR self:yes) NOT author:AutoModerator site:github.com"
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        let query = SearchQuery::new().text("C++ OR (rust) title:x");
+        assert_eq!(query.query, "C\\+\\+ \"OR\" \\(rust\\) title\\:x");
+
+        let query = SearchQuery::new()
+            .phrase("say \"hi\"")
+            .field(SearchField::Url, "https://x.y/z");
+        assert_eq!(query.query, "\"say \\\"hi\\\"\" url:https\\:\\/\\/x.y\\/z");
+    }
+
+    #[test]
+    fn test_build_url() {
+        let query = SearchQuery::new()
+            .title("a&b")
+            .sort(SearchSort::New)
+            .restrict_sr(true)
+            .result_types(&[SearchType::Link, SearchType::Subreddit])
+            .include_over_18(false);
+
+        let url = &mut String::from("search.json");
+        FeedOption::new().limit(5).build_url(url);
+        query.build_url(url);
+
+        assert_eq!(
+            url,
+            "search.json?&limit=5&q=title%3Aa%5C%26b&sort=new&restrict_sr=true&type=link,sr&include_over_18=false&"
+        );
+    }
+}
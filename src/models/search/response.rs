@@ -0,0 +1,120 @@
+//! # Search Responses
+use serde::Deserialize;
+
+use crate::models::about::AboutData;
+use crate::models::response::{BasicThing, Listing};
+use crate::models::submission::SubmissionData;
+use crate::models::subreddit::response::SubredditData;
+
+/// A single search result.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum SearchResult {
+    /// Post
+    #[serde(rename = "t3")]
+    Submission(Box<SubmissionData>),
+    /// Subreddit
+    #[serde(rename = "t5")]
+    Subreddit(Box<SubredditData>),
+    /// User
+    #[serde(rename = "t2")]
+    User(Box<AboutData>),
+}
+
+/// A listing of search results of one type.
+pub type SearchListing = BasicThing<Listing<SearchResult>>;
+
+/// Reddit returns a single listing when searching for one type of result,
+/// and an array of listings when searching for several.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SearchResponse {
+    One(SearchListing),
+    Many(Vec<SearchListing>),
+}
+
+/// The results of a search, one listing per requested type.
+#[derive(Debug, Deserialize)]
+#[serde(from = "SearchResponse")]
+pub struct SearchResults {
+    /// Listings of the results.
+    pub listings: Vec<SearchListing>,
+}
+
+impl From<SearchResponse> for SearchResults {
+    fn from(response: SearchResponse) -> Self {
+        let listings = match response {
+            SearchResponse::One(listing) => vec![listing],
+            SearchResponse::Many(listings) => listings,
+        };
+
+        SearchResults { listings }
+    }
+}
+
+impl SearchResults {
+    fn results(&self) -> impl Iterator<Item = &SearchResult> {
+        self.listings
+            .iter()
+            .flat_map(|listing| listing.data.children.iter())
+    }
+
+    /// Found posts.
+    pub fn submissions(&self) -> impl Iterator<Item = &SubmissionData> {
+        self.results().filter_map(|result| match result {
+            SearchResult::Submission(submission) => Some(submission.as_ref()),
+            _ => None,
+        })
+    }
+
+    /// Found subreddits.
+    pub fn subreddits(&self) -> impl Iterator<Item = &SubredditData> {
+        self.results().filter_map(|result| match result {
+            SearchResult::Subreddit(subreddit) => Some(subreddit.as_ref()),
+            _ => None,
+        })
+    }
+
+    /// Found users.
+    pub fn users(&self) -> impl Iterator<Item = &AboutData> {
+        self.results().filter_map(|result| match result {
+            SearchResult::User(user) => Some(user.as_ref()),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchResults;
+
+    #[test]
+    fn test_one_or_many_listings() {
+        let one = r#"{"kind": "Listing", "data": {"after": null, "before": null, "children": [
+            {"kind": "t5", "data": {"display_name": "rust"}}
+        ]}}"#;
+        let results: SearchResults = serde_json::from_str(one).unwrap();
+        assert_eq!(results.listings.len(), 1);
+        assert_eq!(
+            results.subreddits().next().unwrap().display_name.as_deref(),
+            Some("rust")
+        );
+
+        let many = r#"[
+            {"kind": "Listing", "data": {"after": null, "before": null, "children": [
+                {"kind": "t5", "data": {"display_name": "rust"}}
+            ]}},
+            {"kind": "Listing", "data": {"after": null, "before": null, "children": [
+                {"kind": "t2", "data": {"name": "beanpup_py"}}
+            ]}}
+        ]"#;
+        let results: SearchResults = serde_json::from_str(many).unwrap();
+        assert_eq!(results.listings.len(), 2);
+        assert_eq!(results.subreddits().count(), 1);
+        assert_eq!(
+            results.users().next().unwrap().name.as_deref(),
+            Some("beanpup_py")
+        );
+        assert_eq!(results.submissions().count(), 0);
+    }
+}
@@ -0,0 +1,278 @@
+//! # Stylesheet
+//! Manage the old Reddit stylesheet of a subreddit and its images, header, icon and banner.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::stylesheet::ImageFormat;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let subreddit = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .subreddit("rust")
+//!         .await
+//!         .unwrap();
+//!
+//!     let stylesheet = subreddit.stylesheet();
+//!
+//!     // Upload an image and use it in the stylesheet.
+//!     let image = std::fs::read("crab.png").unwrap();
+//!     stylesheet.upload_image("crab", image, ImageFormat::Png).await.unwrap();
+//!
+//!     let current = stylesheet.get().await.unwrap();
+//!     let css = format!("{}\n.side {{ background: url(%%crab%%); }}", current.stylesheet);
+//!     stylesheet.update(&css, Some("Add crab")).await.unwrap();
+//! }
+//! ```
+
+pub mod response;
+
+extern crate serde_json;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::{multipart, Client};
+use crate::models::response::{BasicThing, JsonResponse};
+use crate::models::stylesheet::response::{ImageUpload, StylesheetData};
+use crate::util::RouxError;
+
+/// The format of an uploaded image.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// PNG
+    Png,
+    /// JPEG
+    Jpg,
+}
+
+impl ImageFormat {
+    /// Gets the request string for the format
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpg => "jpg",
+        }
+    }
+
+    /// Gets the MIME type of the format
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpg => "image/jpeg",
+        }
+    }
+}
+
+/// Where an uploaded image is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum UploadType {
+    Img,
+    Header,
+    Icon,
+    Banner,
+}
+
+impl UploadType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            UploadType::Img => "img",
+            UploadType::Header => "header",
+            UploadType::Icon => "icon",
+            UploadType::Banner => "banner",
+        }
+    }
+}
+
+/// Stylesheet
+pub struct Stylesheet {
+    /// Name of subreddit.
+    pub subreddit: String,
+    host: String,
+    client: Client,
+}
+
+impl Stylesheet {
+    /// Create a new `Stylesheet` instance for a subreddit on a host, e.g. `https://oauth.reddit.com`.
+    pub(crate) fn new(subreddit: &str, host: &str, client: &Client) -> Stylesheet {
+        Stylesheet {
+            subreddit: subreddit.to_owned(),
+            host: host.to_owned(),
+            client: client.to_owned(),
+        }
+    }
+
+    /// Send requests to another host, e.g. a local server for testing.
+    pub fn host(mut self, host: &str) -> Stylesheet {
+        self.host = host.trim_end_matches('/').to_owned();
+        self
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/r/{}/{}", self.host, self.subreddit, path)
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post_json<T: Serialize>(&self, path: &str, form: T) -> Result<(), RouxError> {
+        let response = self
+            .client
+            .post(&self.url(path)[..])
+            .form(&form)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RouxError::Status(response));
+        }
+
+        response
+            .json::<JsonResponse<Value>>()
+            .await?
+            .into_result()?;
+
+        Ok(())
+    }
+
+    #[maybe_async::maybe_async]
+    async fn upload(
+        &self,
+        upload_type: UploadType,
+        name: &str,
+        image: Vec<u8>,
+        format: ImageFormat,
+    ) -> Result<ImageUpload, RouxError> {
+        let file = multipart::Part::bytes(image)
+            .file_name(format!("{}.{}", name, format.as_str()))
+            .mime_str(format.mime_type())?;
+
+        let form = multipart::Form::new()
+            .text("upload_type", upload_type.as_str())
+            .text("img_type", format.as_str())
+            .text("name", name.to_owned())
+            .text(
+                "header",
+                if upload_type == UploadType::Header {
+                    "1"
+                } else {
+                    "0"
+                },
+            )
+            .part("file", file);
+
+        let response = self
+            .client
+            .post(&self.url("api/upload_sr_img")[..])
+            .multipart(form)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RouxError::Status(response));
+        }
+
+        response.json::<ImageUpload>().await?.into_result()
+    }
+
+    /// Get the stylesheet and its images.
+    #[maybe_async::maybe_async]
+    pub async fn get(&self) -> Result<StylesheetData, RouxError> {
+        Ok(self
+            .client
+            .get(&self.url("about/stylesheet/.json")[..])
+            .send()
+            .await?
+            .json::<BasicThing<StylesheetData>>()
+            .await?
+            .data)
+    }
+
+    /// Replace the stylesheet. The reason is shown in the stylesheet's revision history.
+    #[maybe_async::maybe_async]
+    pub async fn update(&self, stylesheet: &str, reason: Option<&str>) -> Result<(), RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("op", "save"),
+            ("stylesheet_contents", stylesheet),
+            ("reason", reason.unwrap_or_default()),
+        ];
+
+        self.post_json("api/subreddit_stylesheet", &form).await
+    }
+
+    /// Upload an image for use in the stylesheet as `url(%%name%%)`, replacing any image
+    /// with the same name.
+    #[maybe_async::maybe_async]
+    pub async fn upload_image(
+        &self,
+        name: &str,
+        image: Vec<u8>,
+        format: ImageFormat,
+    ) -> Result<ImageUpload, RouxError> {
+        self.upload(UploadType::Img, name, image, format).await
+    }
+
+    /// Delete a stylesheet image.
+    #[maybe_async::maybe_async]
+    pub async fn delete_image(&self, name: &str) -> Result<(), RouxError> {
+        let form = [("api_type", "json"), ("img_name", name)];
+
+        self.post_json("api/delete_sr_img", &form).await
+    }
+
+    /// Upload the header image.
+    #[maybe_async::maybe_async]
+    pub async fn upload_header(
+        &self,
+        image: Vec<u8>,
+        format: ImageFormat,
+    ) -> Result<ImageUpload, RouxError> {
+        self.upload(UploadType::Header, "header", image, format)
+            .await
+    }
+
+    /// Delete the header image.
+    #[maybe_async::maybe_async]
+    pub async fn delete_header(&self) -> Result<(), RouxError> {
+        self.post_json("api/delete_sr_header", [("api_type", "json")])
+            .await
+    }
+
+    /// Upload the mobile icon.
+    #[maybe_async::maybe_async]
+    pub async fn upload_icon(
+        &self,
+        image: Vec<u8>,
+        format: ImageFormat,
+    ) -> Result<ImageUpload, RouxError> {
+        self.upload(UploadType::Icon, "icon", image, format).await
+    }
+
+    /// Delete the mobile icon.
+    #[maybe_async::maybe_async]
+    pub async fn delete_icon(&self) -> Result<(), RouxError> {
+        self.post_json("api/delete_sr_icon", [("api_type", "json")])
+            .await
+    }
+
+    /// Upload the mobile banner.
+    #[maybe_async::maybe_async]
+    pub async fn upload_banner(
+        &self,
+        image: Vec<u8>,
+        format: ImageFormat,
+    ) -> Result<ImageUpload, RouxError> {
+        self.upload(UploadType::Banner, "banner", image, format)
+            .await
+    }
+
+    /// Delete the mobile banner.
+    #[maybe_async::maybe_async]
+    pub async fn delete_banner(&self) -> Result<(), RouxError> {
+        self.post_json("api/delete_sr_banner", [("api_type", "json")])
+            .await
+    }
+}
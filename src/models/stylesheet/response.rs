@@ -0,0 +1,85 @@
+//! # Stylesheet Responses
+use serde::Deserialize;
+
+use crate::util::{ApiError, RouxError};
+
+/// An image uploaded for use in the stylesheet.
+#[derive(Debug, Deserialize)]
+pub struct StylesheetImage {
+    /// URL of the image
+    pub url: String,
+    /// How to reference the image in the stylesheet, e.g. `url(%%name%%)`
+    pub link: String,
+    /// Name
+    pub name: String,
+}
+
+/// StylesheetData
+#[derive(Debug, Deserialize)]
+pub struct StylesheetData {
+    /// The fullname of the subreddit
+    pub subreddit_id: String,
+    /// The CSS of the stylesheet
+    pub stylesheet: String,
+    /// Images uploaded for use in the stylesheet
+    #[serde(default)]
+    pub images: Vec<StylesheetImage>,
+}
+
+/// The response of an image upload.
+#[derive(Debug, Deserialize)]
+pub struct ImageUpload {
+    /// The URL of the uploaded image
+    #[serde(default)]
+    pub img_src: String,
+    /// Error codes
+    #[serde(default)]
+    pub errors: Vec<String>,
+    /// Error messages, in the same order as the codes
+    #[serde(default)]
+    pub errors_values: Vec<String>,
+}
+
+impl ImageUpload {
+    /// Turns the reported errors into a `RouxError::Api`, otherwise returns the upload.
+    #[allow(clippy::result_large_err)]
+    pub fn into_result(self) -> Result<ImageUpload, RouxError> {
+        if self.errors.is_empty() {
+            return Ok(self);
+        }
+
+        Err(RouxError::Api(
+            self.errors
+                .iter()
+                .enumerate()
+                .map(|(i, code)| ApiError {
+                    code: code.to_owned(),
+                    message: self.errors_values.get(i).cloned().unwrap_or_default(),
+                    field: None,
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImageUpload;
+    use crate::util::RouxError;
+
+    #[test]
+    fn test_image_upload_errors() {
+        let upload = serde_json::from_str::<ImageUpload>(
+            r#"{"errors": ["IMAGE_ERROR"], "img_src": "", "errors_values": ["too big"]}"#,
+        )
+        .unwrap();
+
+        match upload.into_result() {
+            Err(RouxError::Api(errors)) => {
+                assert_eq!(errors[0].code, "IMAGE_ERROR");
+                assert_eq!(errors[0].message, "too big");
+            }
+            _ => panic!("expected an api error"),
+        }
+    }
+}
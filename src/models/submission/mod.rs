@@ -2,7 +2,9 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::models::moderation::response::{ModReport, UserReport};
 use crate::models::response::BasicListing;
+use crate::models::richtext::RichtextSpan;
 
 /// SubmissionsData
 #[derive(Debug, Serialize, Deserialize)]
@@ -34,10 +36,16 @@ pub struct SubmissionData {
     /// - qa
     /// - confidence
     pub suggested_sort: Option<String>,
-    // skipped user_reports and secure_media
+    /// Reports made by users, if the user is a moderator of this subreddit.
+    pub user_reports: Option<Vec<UserReport>>,
+    /// Reports made by moderators, if the user is a moderator of this subreddit.
+    pub mod_reports: Option<Vec<ModReport>>,
+    // skipped secure_media
     /// If this post is flaired, this set to `Some(FLAIR TEXT)`. Otherwise, it is `None`.
     /// Link flairs **can** be empty strings.
     pub link_flair_text: Option<String>,
+    /// The parts of the link flair, if it is a richtext flair.
+    pub link_flair_richtext: Option<Vec<RichtextSpan>>,
     /// The ID of the post in base-36 form, as used in Reddit's links.
     pub id: String,
     // skipped from_kind
@@ -122,6 +130,8 @@ pub struct SubmissionData {
     /// The text of the author's flair, if present. Can be an empty string if the flair is present
     /// but contains no text.
     pub author_flair_text: Option<String>,
+    /// The parts of the author's flair, if it is a richtext flair.
+    pub author_flair_richtext: Option<Vec<RichtextSpan>>,
     /// This is `true` if the post is from a quarantined subreddit.
     pub quarantine: bool,
     /// The title of the post.
@@ -134,6 +144,38 @@ pub struct SubmissionData {
     pub visited: bool,
     /// The number of reports, if the user is a moderator of this subreddit.
     pub num_reports: Option<u64>,
+    /// This is `true` if this submission can be crossposted.
+    pub is_crosspostable: Option<bool>,
+    /// The number of times this submission has been crossposted.
+    pub num_crossposts: Option<u64>,
+    /// The fullname of the submission this was crossposted from, if this is a crosspost.
+    pub crosspost_parent: Option<String>,
+    /// The data of the submission this was crossposted from, if this is a crosspost.
+    pub crosspost_parent_list: Option<Vec<SubmissionData>>,
+}
+
+impl SubmissionData {
+    /// Returns `true` if this submission is a crosspost of another submission.
+    pub fn is_crosspost(&self) -> bool {
+        self.crosspost_parent.is_some()
+    }
+
+    /// Get the submission this was crossposted from, if this is a crosspost.
+    pub fn crosspost_parent_data(&self) -> Option<&SubmissionData> {
+        self.crosspost_parent_list.as_ref()?.first()
+    }
+
+    /// Follow the crosspost chain back to the original submission.
+    /// Returns `None` if this submission is not a crosspost.
+    pub fn crosspost_original(&self) -> Option<&SubmissionData> {
+        let mut original = self.crosspost_parent_data()?;
+
+        while let Some(parent) = original.crosspost_parent_data() {
+            original = parent;
+        }
+
+        Some(original)
+    }
 }
 
 /// Submissions
@@ -64,15 +64,31 @@
 //! }
 //! ```
 pub mod response;
+pub mod set;
 extern crate serde_json;
+use serde::Serialize;
+use serde_json::Value;
 
-use crate::models::subreddit::response::{SubredditData, SubredditResponse, SubredditsData};
+use crate::models::subreddit::response::{
+    RecommendedSubreddit, SubredditData, SubredditNames, SubredditResponse, SubredditSettings,
+    SubredditsData,
+};
 
-use crate::client::Client;
+use crate::client::{Client, Response};
 use crate::util::defaults::default_client;
-use crate::util::{FeedOption, RouxError};
+use crate::util::{url, FeedOption, RouxError};
 
-use crate::models::{Comments, Moderators, Submissions};
+use crate::models::moderation::{StickySlot, ThingType};
+use crate::models::modlog::{ModActionType, ModLogStream};
+use crate::models::response::{BasicThing, JsonResponse, Listing};
+use crate::models::rule::response::{PostRequirements, RemovalReasons, RuleKind, Rules};
+use crate::models::search::SearchQuery;
+use crate::models::submission::SubmissionData;
+use crate::models::widget::{Widget, Widgets};
+use crate::models::{
+    Comments, Emoji, Flair, ModLog, ModQueue, Moderators, Relationships, Saved, Stylesheet,
+    Submissions, SubredditTraffic, Wiki,
+};
 
 /// Access subreddits API
 pub struct Subreddits;
@@ -85,7 +101,10 @@ impl Subreddits {
         limit: Option<u32>,
         options: Option<FeedOption>,
     ) -> Result<SubredditsData, RouxError> {
-        let url = &mut format!("https://www.reddit.com/subreddits/search.json?q={}", name);
+        let url = &mut format!(
+            "https://www.reddit.com/subreddits/search.json?q={}",
+            url::encode(name)
+        );
 
         if let Some(limit) = limit {
             url.push_str(&format!("&limit={}", limit));
@@ -104,6 +123,164 @@ impl Subreddits {
             .json::<SubredditsData>()
             .await?)
     }
+
+    #[maybe_async::maybe_async]
+    async fn get_listing(
+        path: &str,
+        options: Option<FeedOption>,
+    ) -> Result<SubredditsData, RouxError> {
+        let url = &mut format!("https://www.reddit.com/subreddits/{}.json", path);
+
+        options.unwrap_or_default().build_url(url);
+
+        Ok(default_client()
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<SubredditsData>()
+            .await?)
+    }
+
+    /// Get the most popular subreddits.
+    #[maybe_async::maybe_async]
+    pub async fn popular(options: Option<FeedOption>) -> Result<SubredditsData, RouxError> {
+        Self::get_listing("popular", options).await
+    }
+
+    /// Get the newest subreddits.
+    #[maybe_async::maybe_async]
+    pub async fn latest(options: Option<FeedOption>) -> Result<SubredditsData, RouxError> {
+        Self::get_listing("new", options).await
+    }
+
+    /// Get the default subreddits.
+    #[maybe_async::maybe_async]
+    pub async fn defaults(options: Option<FeedOption>) -> Result<SubredditsData, RouxError> {
+        Self::get_listing("default", options).await
+    }
+
+    /// Get the subreddits only accessible with Reddit Premium.
+    #[maybe_async::maybe_async]
+    pub async fn gold(options: Option<FeedOption>) -> Result<SubredditsData, RouxError> {
+        Self::get_listing("gold", options).await
+    }
+
+    /// Get subreddits whose name starts with `query`, as shown in the search bar.
+    #[maybe_async::maybe_async]
+    pub async fn autocomplete(
+        query: &str,
+        include_over_18: bool,
+        options: Option<FeedOption>,
+    ) -> Result<SubredditsData, RouxError> {
+        let url = &mut String::from("https://www.reddit.com/api/subreddit_autocomplete_v2.json");
+
+        options.unwrap_or_default().build_url(url);
+        url.push_str(&format!(
+            "query={}&include_over_18={}&include_profiles=false&",
+            url::encode(query),
+            include_over_18
+        ));
+
+        Ok(default_client()
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<SubredditsData>()
+            .await?)
+    }
+
+    /// Get the data of subreddits by name.
+    #[maybe_async::maybe_async]
+    async fn info(
+        names: &[String],
+        options: Option<FeedOption>,
+    ) -> Result<SubredditsData, RouxError> {
+        if names.is_empty() {
+            return Ok(BasicThing {
+                kind: Some(String::from("Listing")),
+                data: Listing {
+                    modhash: None,
+                    dist: Some(0),
+                    after: None,
+                    before: None,
+                    children: Vec::new(),
+                },
+            });
+        }
+
+        let url = &mut String::from("https://www.reddit.com/api/info.json");
+
+        options.unwrap_or_default().build_url(url);
+        url.push_str(&format!("sr_name={}&", encode_names(names)));
+
+        Ok(default_client()
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<SubredditsData>()
+            .await?)
+    }
+
+    /// Get subreddits whose name starts with `query`, or only the one named `query` if `exact`.
+    #[maybe_async::maybe_async]
+    pub async fn search_names(
+        query: &str,
+        exact: bool,
+        include_over_18: bool,
+        options: Option<FeedOption>,
+    ) -> Result<SubredditsData, RouxError> {
+        let url = format!(
+            "https://www.reddit.com/api/search_reddit_names.json?query={}&exact={}&include_over_18={}",
+            url::encode(query),
+            exact,
+            include_over_18
+        );
+
+        let names = default_client()
+            .get(url)
+            .send()
+            .await?
+            .json::<SubredditNames>()
+            .await?
+            .names;
+
+        Self::info(&names, options).await
+    }
+
+    /// Get subreddits similar to `names`, leaving out the ones in `omit`.
+    #[maybe_async::maybe_async]
+    pub async fn recommend(
+        names: &[&str],
+        omit: &[&str],
+        options: Option<FeedOption>,
+    ) -> Result<SubredditsData, RouxError> {
+        let url = format!(
+            "https://www.reddit.com/api/recommend/sr/{}.json?omit={}",
+            encode_names(names),
+            encode_names(omit)
+        );
+
+        let names: Vec<String> = default_client()
+            .get(url)
+            .send()
+            .await?
+            .json::<Vec<RecommendedSubreddit>>()
+            .await?
+            .into_iter()
+            .map(|recommended| recommended.sr_name)
+            .collect();
+
+        Self::info(&names, options).await
+    }
+}
+
+/// Percent-encodes subreddit names and joins them with commas.
+fn encode_names<T: AsRef<str>>(names: &[T]) -> String {
+    names
+        .iter()
+        .map(|name| url::encode(name.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 /// Subreddit
@@ -111,17 +288,21 @@ pub struct Subreddit {
     /// Name of subreddit.
     pub name: String,
     url: String,
+    /// Extra query parameters of feeds, e.g. `g=US&`.
+    feed_params: String,
     client: Client,
 }
 
 impl Subreddit {
     /// Create a new `Subreddit` instance.
+    /// Use `SubredditSet` for combined feeds like `rust+programming` or `all-memes`.
     pub fn new(name: &str) -> Subreddit {
         let subreddit_url = format!("https://www.reddit.com/r/{}", name);
 
         Subreddit {
             name: name.to_owned(),
             url: subreddit_url,
+            feed_params: String::new(),
             client: default_client(),
         }
     }
@@ -134,22 +315,144 @@ impl Subreddit {
         Subreddit {
             name: name.to_owned(),
             url: subreddit_url,
+            feed_params: String::new(),
             client: client.to_owned(),
         }
     }
 
-    /// Get moderators (requires authentication)
+    /// The url of the site this subreddit is accessed through, without the `/r/{name}` part.
+    fn root_url(&self) -> &str {
+        self.url
+            .split_once("/r/")
+            .map_or(&self.url[..], |(root, _)| root)
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
+        let post_url = format!("{}/{}", self.root_url(), url);
+
+        match self.client.post(&post_url[..]).form(&form).send().await {
+            Ok(response) => Ok(response),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post_json<T: Serialize>(&self, url: &str, form: T) -> Result<(), RouxError> {
+        self.post(url, form)
+            .await?
+            .json::<JsonResponse<Value>>()
+            .await?
+            .into_result()?;
+
+        Ok(())
+    }
+
+    /// Get a handle to the flair of this subreddit (requires authentication).
+    pub fn flair(&self) -> Flair {
+        Flair::new(&self.name, &self.url, &self.client)
+    }
+
+    /// Get a handle to the wiki of this subreddit.
+    pub fn wiki(&self) -> Wiki {
+        Wiki::new(&self.name, &self.url, &self.client)
+    }
+
+    /// Get a handle to the stylesheet and images of this subreddit (requires authentication).
+    pub fn stylesheet(&self) -> Stylesheet {
+        Stylesheet::new(&self.name, self.root_url(), &self.client)
+    }
+
+    /// Get a handle to the custom emoji of this subreddit (requires authentication).
+    pub fn emoji(&self) -> Emoji {
+        Emoji::new(&self.name, self.root_url(), &self.client)
+    }
+
+    /// Get moderators and their permissions (requires authentication)
     #[maybe_async::maybe_async]
     pub async fn moderators(&self) -> Result<Moderators, RouxError> {
+        self.moderators_paged(None).await
+    }
+
+    /// Get a page of moderators and their permissions (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn moderators_paged(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Moderators, RouxError> {
+        let url = &mut format!("{}/about/moderators/.json", self.url);
+
+        options.unwrap_or_default().build_url(url);
+
         Ok(self
             .client
-            .get(&format!("{}/about/moderators/.json", self.url))
+            .get(url.as_str())
             .send()
             .await?
             .json::<Moderators>()
             .await?)
     }
 
+    #[maybe_async::maybe_async]
+    async fn get_relationships(
+        &self,
+        ty: &str,
+        options: Option<FeedOption>,
+    ) -> Result<Relationships, RouxError> {
+        let url = &mut format!("{}/about/{}/.json", self.url, ty);
+
+        if let Some(options) = options {
+            options.build_url(url);
+        }
+
+        Ok(self
+            .client
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<Relationships>()
+            .await?)
+    }
+
+    /// Get banned users (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn banned(&self, options: Option<FeedOption>) -> Result<Relationships, RouxError> {
+        self.get_relationships("banned", options).await
+    }
+
+    /// Get muted users (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn muted(&self, options: Option<FeedOption>) -> Result<Relationships, RouxError> {
+        self.get_relationships("muted", options).await
+    }
+
+    /// Get approved users (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn contributors(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Relationships, RouxError> {
+        self.get_relationships("contributors", options).await
+    }
+
+    /// Get users banned from the wiki (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn wiki_banned(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Relationships, RouxError> {
+        self.get_relationships("wikibanned", options).await
+    }
+
+    /// Get approved wiki contributors (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn wiki_contributors(
+        &self,
+        options: Option<FeedOption>,
+    ) -> Result<Relationships, RouxError> {
+        self.get_relationships("wikicontributors", options).await
+    }
+
     /// Get subreddit data.
     #[maybe_async::maybe_async]
     pub async fn about(&self) -> Result<SubredditData, RouxError> {
@@ -163,6 +466,419 @@ impl Subreddit {
             .data)
     }
 
+    /// Get the settings of this subreddit (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn settings(&self) -> Result<SubredditSettings, RouxError> {
+        Ok(self
+            .client
+            .get(format!("{}/about/edit/.json", self.url))
+            .send()
+            .await?
+            .json::<BasicThing<SubredditSettings>>()
+            .await?
+            .data)
+    }
+
+    /// Update the settings of this subreddit (requires authentication)
+    ///
+    /// All settings are sent, so fetch them with `settings` and change the fields you need,
+    /// otherwise Reddit resets the missing ones.
+    #[maybe_async::maybe_async]
+    pub async fn update_settings(&self, settings: &SubredditSettings) -> Result<(), RouxError> {
+        self.post_json("api/site_admin", &settings.build_form())
+            .await
+    }
+
+    /// Get the traffic statistics of this subreddit (requires authentication unless public)
+    #[maybe_async::maybe_async]
+    pub async fn traffic(&self) -> Result<SubredditTraffic, RouxError> {
+        Ok(self
+            .client
+            .get(format!("{}/about/traffic/.json", self.url))
+            .send()
+            .await?
+            .json::<SubredditTraffic>()
+            .await?)
+    }
+
+    /// Get the rules of this subreddit.
+    #[maybe_async::maybe_async]
+    pub async fn rules(&self) -> Result<Rules, RouxError> {
+        Ok(self
+            .client
+            .get(format!("{}/about/rules/.json", self.url))
+            .send()
+            .await?
+            .json::<Rules>()
+            .await?)
+    }
+
+    /// Add a rule (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn add_rule(
+        &self,
+        short_name: &str,
+        kind: RuleKind,
+        description: Option<&str>,
+        violation_reason: Option<&str>,
+    ) -> Result<(), RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("r", &self.name),
+            ("short_name", short_name),
+            ("kind", kind.as_str()),
+            ("description", description.unwrap_or_default()),
+            ("violation_reason", violation_reason.unwrap_or(short_name)),
+        ];
+
+        self.post_json("api/add_subreddit_rule", &form).await
+    }
+
+    /// Update the rule named `old_short_name` (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn update_rule(
+        &self,
+        old_short_name: &str,
+        short_name: &str,
+        kind: RuleKind,
+        description: Option<&str>,
+        violation_reason: Option<&str>,
+    ) -> Result<(), RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("r", &self.name),
+            ("old_short_name", old_short_name),
+            ("short_name", short_name),
+            ("kind", kind.as_str()),
+            ("description", description.unwrap_or_default()),
+            ("violation_reason", violation_reason.unwrap_or(short_name)),
+        ];
+
+        self.post_json("api/update_subreddit_rule", &form).await
+    }
+
+    /// Remove a rule (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn remove_rule(&self, short_name: &str) -> Result<(), RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("r", &self.name),
+            ("short_name", short_name),
+        ];
+
+        self.post_json("api/remove_subreddit_rule", &form).await
+    }
+
+    /// Reorder the rules by their short names (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn reorder_rules(&self, short_names: &[&str]) -> Result<(), RouxError> {
+        let order = short_names.join(",");
+        let form = [
+            ("api_type", "json"),
+            ("r", &self.name),
+            ("new_rule_order", &order),
+        ];
+
+        self.post_json("api/reorder_subreddit_rules", &form).await
+    }
+
+    /// Get the removal reasons (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn removal_reasons(&self) -> Result<RemovalReasons, RouxError> {
+        Ok(self
+            .client
+            .get(format!(
+                "{}/api/v1/{}/removal_reasons",
+                self.root_url(),
+                self.name
+            ))
+            .send()
+            .await?
+            .json::<RemovalReasons>()
+            .await?)
+    }
+
+    /// Add a removal reason (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn add_removal_reason(&self, title: &str, message: &str) -> Result<(), RouxError> {
+        let form = [("title", title), ("message", message)];
+        let response = self
+            .post(&format!("api/v1/{}/removal_reasons", self.name), &form)
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Update a removal reason (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn update_removal_reason(
+        &self,
+        id: &str,
+        title: &str,
+        message: &str,
+    ) -> Result<(), RouxError> {
+        let form = [("title", title), ("message", message)];
+        let response = self
+            .client
+            .put(format!(
+                "{}/api/v1/{}/removal_reasons/{}",
+                self.root_url(),
+                self.name,
+                id
+            ))
+            .form(&form)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Delete a removal reason (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn delete_removal_reason(&self, id: &str) -> Result<(), RouxError> {
+        let response = self
+            .client
+            .delete(format!(
+                "{}/api/v1/{}/removal_reasons/{}",
+                self.root_url(),
+                self.name,
+                id
+            ))
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Get the requirements submissions must meet (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn post_requirements(&self) -> Result<PostRequirements, RouxError> {
+        Ok(self
+            .client
+            .get(format!(
+                "{}/api/v1/{}/post_requirements",
+                self.root_url(),
+                self.name
+            ))
+            .send()
+            .await?
+            .json::<PostRequirements>()
+            .await?)
+    }
+
+    /// Get the sidebar and topbar widgets.
+    #[maybe_async::maybe_async]
+    pub async fn widgets(&self) -> Result<Widgets, RouxError> {
+        Ok(self
+            .client
+            .get(format!("{}/api/widgets", self.url))
+            .send()
+            .await?
+            .json::<Widgets>()
+            .await?)
+    }
+
+    /// Add a widget to the sidebar, returning it with its ID (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn create_widget(&self, widget: &Widget) -> Result<Widget, RouxError> {
+        let response = self
+            .client
+            .post(format!("{}/api/widget", self.url))
+            .json(widget)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(response.json::<Widget>().await?)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Replace a widget (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn update_widget(&self, id: &str, widget: &Widget) -> Result<Widget, RouxError> {
+        let response = self
+            .client
+            .put(format!("{}/api/widget/{}", self.url, id))
+            .json(widget)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(response.json::<Widget>().await?)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Delete a widget (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn delete_widget(&self, id: &str) -> Result<(), RouxError> {
+        let response = self
+            .client
+            .delete(format!("{}/api/widget/{}", self.url, id))
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    /// Set the order of the sidebar widgets by ID (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn reorder_widgets(&self, order: &[&str]) -> Result<(), RouxError> {
+        let response = self
+            .client
+            .patch(format!("{}/api/widget_order/sidebar", self.url))
+            .json(order)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn get_mod_listing(
+        &self,
+        ty: &str,
+        only: Option<ThingType>,
+        options: Option<FeedOption>,
+    ) -> Result<ModQueue, RouxError> {
+        let url = &mut format!("{}/about/{}/.json", self.url, ty);
+
+        options.unwrap_or_default().build_url(url);
+
+        if let Some(only) = only {
+            url.push_str(&format!("only={}", only.as_str()));
+        }
+
+        Ok(self
+            .client
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<ModQueue>()
+            .await?)
+    }
+
+    /// Get items in the modqueue (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn modqueue(
+        &self,
+        only: Option<ThingType>,
+        options: Option<FeedOption>,
+    ) -> Result<ModQueue, RouxError> {
+        self.get_mod_listing("modqueue", only, options).await
+    }
+
+    /// Get reported items (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn reports(
+        &self,
+        only: Option<ThingType>,
+        options: Option<FeedOption>,
+    ) -> Result<ModQueue, RouxError> {
+        self.get_mod_listing("reports", only, options).await
+    }
+
+    /// Get items removed as spam (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn spam(
+        &self,
+        only: Option<ThingType>,
+        options: Option<FeedOption>,
+    ) -> Result<ModQueue, RouxError> {
+        self.get_mod_listing("spam", only, options).await
+    }
+
+    /// Get recently edited items (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn edited(
+        &self,
+        only: Option<ThingType>,
+        options: Option<FeedOption>,
+    ) -> Result<ModQueue, RouxError> {
+        self.get_mod_listing("edited", only, options).await
+    }
+
+    /// Get items that have not been moderated yet (requires authentication)
+    #[maybe_async::maybe_async]
+    pub async fn unmoderated(
+        &self,
+        only: Option<ThingType>,
+        options: Option<FeedOption>,
+    ) -> Result<ModQueue, RouxError> {
+        self.get_mod_listing("unmoderated", only, options).await
+    }
+
+    /// Get the moderation log (requires authentication), optionally filtered by
+    /// moderator and action type.
+    #[maybe_async::maybe_async]
+    pub async fn mod_log(
+        &self,
+        moderator: Option<&str>,
+        action: Option<ModActionType>,
+        options: Option<FeedOption>,
+    ) -> Result<ModLog, RouxError> {
+        let url = &mut format!("{}/about/log/.json", self.url);
+
+        options.unwrap_or_default().build_url(url);
+
+        if let Some(moderator) = moderator {
+            url.push_str(&format!("mod={}&", url::encode(moderator)));
+        }
+
+        if let Some(action) = action {
+            url.push_str(&format!("type={}&", action.as_str()));
+        }
+
+        Ok(self
+            .client
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<ModLog>()
+            .await?)
+    }
+
+    /// Poll the moderation log for new entries (requires authentication).
+    pub fn mod_log_stream(
+        &self,
+        moderator: Option<&str>,
+        action: Option<ModActionType>,
+    ) -> ModLogStream<'_> {
+        ModLogStream::new(self, moderator, action)
+    }
+
+    fn feed_url(&self, ty: &str, limit: u32, options: Option<FeedOption>) -> String {
+        let mut url = format!("{}/{}.json", self.url, ty);
+
+        options.unwrap_or_default().build_url(&mut url);
+        url.push_str(&format!("limit={}&{}", limit, self.feed_params));
+
+        url
+    }
+
     #[maybe_async::maybe_async]
     async fn get_feed(
         &self,
@@ -170,15 +886,11 @@ impl Subreddit {
         limit: u32,
         options: Option<FeedOption>,
     ) -> Result<Submissions, RouxError> {
-        let url = &mut format!("{}/{}.json?limit={}", self.url, ty, limit);
-
-        if let Some(options) = options {
-            options.build_url(url);
-        }
+        let url = self.feed_url(ty, limit, options);
 
         Ok(self
             .client
-            .get(&url.to_owned())
+            .get(url.as_str())
             .send()
             .await?
             .json::<Submissions>()
@@ -267,6 +979,101 @@ impl Subreddit {
         self.get_feed("new", limit, options).await
     }
 
+    /// Get controversial posts. Use `FeedOption::period` to set the time period.
+    #[maybe_async::maybe_async]
+    pub async fn controversial(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_feed("controversial", limit, options).await
+    }
+
+    /// Search posts of this subreddit.
+    /// Results are restricted to this subreddit unless `SearchQuery::restrict_sr` is set to false.
+    #[maybe_async::maybe_async]
+    pub async fn search(
+        &self,
+        query: &SearchQuery,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        let url = &mut format!("{}/search.json", self.url);
+
+        options.unwrap_or_default().build_url(url);
+        query.build_url(url);
+
+        if query.restrict_sr.is_none() {
+            url.push_str("restrict_sr=true&");
+        }
+
+        Ok(self
+            .client
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<Submissions>()
+            .await?)
+    }
+
+    /// Get recently gilded posts and comments.
+    #[maybe_async::maybe_async]
+    pub async fn gilded(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Saved, RouxError> {
+        let url = &mut format!("{}/gilded.json", self.url);
+
+        options.unwrap_or_default().build_url(url);
+        url.push_str(&format!("limit={}&", limit));
+
+        Ok(self
+            .client
+            .get(url.as_str())
+            .send()
+            .await?
+            .json::<Saved>()
+            .await?)
+    }
+
+    /// Get a submission page that `url` redirects to.
+    #[maybe_async::maybe_async]
+    async fn get_redirected_submission(&self, url: &str) -> Result<SubmissionData, RouxError> {
+        let response = self.client.get(url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(RouxError::Status(response));
+        }
+
+        // Submission pages are the submission followed by its comments.
+        let (submission, _) = response.json::<(Submissions, Value)>().await?;
+
+        match submission.data.children.into_iter().next() {
+            Some(submission) => Ok(submission.data),
+            None => Err(RouxError::MissingData(String::from(
+                "submission page without a submission",
+            ))),
+        }
+    }
+
+    /// Get a random post. Fails if the subreddit disabled random posts.
+    #[maybe_async::maybe_async]
+    pub async fn random(&self) -> Result<SubmissionData, RouxError> {
+        self.get_redirected_submission(&format!("{}/random/.json", self.url))
+            .await
+    }
+
+    /// Get a stickied post. Fails if there is none in the slot.
+    #[maybe_async::maybe_async]
+    pub async fn sticky(&self, slot: StickySlot) -> Result<SubmissionData, RouxError> {
+        self.get_redirected_submission(&format!(
+            "{}/about/sticky/.json?num={}",
+            self.url,
+            slot.as_str()
+        ))
+        .await
+    }
+
     /// Get latest comments.
     #[maybe_async::maybe_async]
     pub async fn latest_comments(
@@ -292,8 +1099,32 @@ impl Subreddit {
 
 #[cfg(test)]
 mod tests {
+    use super::encode_names;
     use super::Subreddit;
     use super::Subreddits;
+    use crate::util::FeedOption;
+
+    #[test]
+    fn test_encode_names() {
+        assert_eq!(encode_names(&["rust", "golang"]), "rust,golang");
+        assert_eq!(encode_names(&["a b", "c&d"]), "a%20b,c%26d");
+        assert_eq!(encode_names::<&str>(&[]), "");
+    }
+
+    #[test]
+    fn test_feed_url() {
+        let mut subreddit = Subreddit::new("popular");
+        subreddit.feed_params = String::from("g=US&");
+
+        assert_eq!(
+            subreddit.feed_url("hot", 25, Some(FeedOption::new().after("t3_x"))),
+            "https://www.reddit.com/r/popular/hot.json?&after=t3_x&limit=25&g=US&"
+        );
+        assert_eq!(
+            subreddit.feed_url("top", 10, None),
+            "https://www.reddit.com/r/popular/top.json?&limit=10&g=US&"
+        );
+    }
 
     #[maybe_async::test(feature = "blocking", async(not(feature = "blocking"), tokio::test))]
     async fn test_no_auth() {
@@ -309,6 +1140,12 @@ mod tests {
         let top = subreddit.top(25, None).await;
         assert!(top.is_ok());
 
+        let controversial = subreddit.controversial(25, None).await;
+        assert!(controversial.is_ok());
+
+        let gilded = subreddit.gilded(25, None).await;
+        assert!(gilded.is_ok());
+
         let latest_comments = subreddit.latest_comments(None, Some(25)).await;
         assert!(latest_comments.is_ok());
 
@@ -330,5 +1167,12 @@ mod tests {
         let subreddits = Subreddits::search("rust", Some(subreddits_limit), None).await;
         assert!(subreddits.is_ok());
         assert!(subreddits.unwrap().data.children.len() == subreddits_limit as usize);
+
+        // Test subreddit discovery
+        let popular = Subreddits::popular(Some(FeedOption::new().limit(5))).await;
+        assert!(popular.is_ok());
+
+        let autocomplete = Subreddits::autocomplete("rus", false, None).await;
+        assert!(autocomplete.is_ok());
     }
 }
@@ -1,7 +1,9 @@
 //! # Subreddit Responses
 use serde::Deserialize;
+use serde_json::{Map, Value};
 
 use crate::models::response::BasicListing;
+use crate::models::richtext::RichtextSpan;
 
 /// SubredditResponse
 #[derive(Debug, Deserialize)]
@@ -23,16 +25,8 @@ pub enum AccountsActive {
     Vector(Vec<u8>),
 }
 
-/// If the API user has user flair in this subreddit, and its user_flair_type is richtext,
-/// this will be an array containing two string elements which define the user's flair.
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
-pub struct RichtextFlair {
-    /// contains the string text
-    e: String,
-    /// contains the literal string that comprises the user's flair.
-    t: String,
-}
+/// A part of a richtext flair, e.g. text or an emoji.
+pub type RichtextFlair = RichtextSpan;
 
 /// SubredditData
 #[derive(Debug, Deserialize)]
@@ -327,3 +321,449 @@ pub struct SubredditData {
 
 /// Subreddits
 pub type SubredditsData = BasicListing<SubredditData>;
+
+/// Names of subreddits matching a query.
+#[derive(Debug, Deserialize)]
+pub struct SubredditNames {
+    /// Names of the subreddits
+    pub names: Vec<String>,
+}
+
+/// A recommended subreddit.
+#[derive(Debug, Deserialize)]
+pub struct RecommendedSubreddit {
+    /// Name of the subreddit
+    pub sr_name: String,
+}
+
+/// Who can view and submit to a subreddit.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum SubredditType {
+    /// Anyone can view and submit
+    Public,
+    /// Anyone can view, only approved users can submit
+    Restricted,
+    /// Only approved users can view and submit
+    Private,
+    /// Only Reddit employees can view and submit
+    EmployeesOnly,
+    /// Archived, nobody can submit
+    Archived,
+    /// A type roux does not know about yet.
+    Other(String),
+}
+
+impl SubredditType {
+    /// Gets the request string for the type
+    pub fn as_str(&self) -> &str {
+        match self {
+            SubredditType::Public => "public",
+            SubredditType::Restricted => "restricted",
+            SubredditType::Private => "private",
+            SubredditType::EmployeesOnly => "employees_only",
+            SubredditType::Archived => "archived",
+            SubredditType::Other(ty) => ty,
+        }
+    }
+}
+
+impl From<String> for SubredditType {
+    fn from(ty: String) -> Self {
+        match ty.as_str() {
+            "public" => SubredditType::Public,
+            "restricted" => SubredditType::Restricted,
+            "private" => SubredditType::Private,
+            "employees_only" => SubredditType::EmployeesOnly,
+            "archived" => SubredditType::Archived,
+            _ => SubredditType::Other(ty),
+        }
+    }
+}
+
+/// The kind of submissions allowed in a subreddit.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum LinkType {
+    /// Links and self posts
+    Any,
+    /// Only links
+    Link,
+    /// Only self posts
+    SelfPost,
+    /// A link type roux does not know about yet.
+    Other(String),
+}
+
+impl LinkType {
+    /// Gets the request string for the link type
+    pub fn as_str(&self) -> &str {
+        match self {
+            LinkType::Any => "any",
+            LinkType::Link => "link",
+            LinkType::SelfPost => "self",
+            LinkType::Other(ty) => ty,
+        }
+    }
+}
+
+impl From<String> for LinkType {
+    fn from(ty: String) -> Self {
+        match ty.as_str() {
+            "any" => LinkType::Any,
+            "link" => LinkType::Link,
+            "self" => LinkType::SelfPost,
+            _ => LinkType::Other(ty),
+        }
+    }
+}
+
+/// The strength of a spam filter.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum SpamFilter {
+    /// Filter likely spam
+    Low,
+    /// Filter more aggressively
+    High,
+    /// Filter everything until approved
+    All,
+    /// A filter strength roux does not know about yet.
+    Other(String),
+}
+
+impl SpamFilter {
+    /// Gets the request string for the filter strength
+    pub fn as_str(&self) -> &str {
+        match self {
+            SpamFilter::Low => "low",
+            SpamFilter::High => "high",
+            SpamFilter::All => "all",
+            SpamFilter::Other(filter) => filter,
+        }
+    }
+}
+
+impl From<String> for SpamFilter {
+    fn from(filter: String) -> Self {
+        match filter.as_str() {
+            "low" => SpamFilter::Low,
+            "high" => SpamFilter::High,
+            "all" => SpamFilter::All,
+            _ => SpamFilter::Other(filter),
+        }
+    }
+}
+
+/// Who can edit the wiki of a subreddit.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum WikiMode {
+    /// The wiki is disabled
+    Disabled,
+    /// Only moderators
+    ModOnly,
+    /// Anyone meeting the age and karma requirements
+    Anyone,
+    /// A wiki mode roux does not know about yet.
+    Other(String),
+}
+
+impl WikiMode {
+    /// Gets the request string for the wiki mode
+    pub fn as_str(&self) -> &str {
+        match self {
+            WikiMode::Disabled => "disabled",
+            WikiMode::ModOnly => "modonly",
+            WikiMode::Anyone => "anyone",
+            WikiMode::Other(mode) => mode,
+        }
+    }
+}
+
+impl From<String> for WikiMode {
+    fn from(mode: String) -> Self {
+        match mode.as_str() {
+            "disabled" => WikiMode::Disabled,
+            "modonly" => WikiMode::ModOnly,
+            "anyone" => WikiMode::Anyone,
+            _ => WikiMode::Other(mode),
+        }
+    }
+}
+
+/// The settings of a subreddit, as seen by its moderators.
+///
+/// `api/site_admin` resets every setting missing from its form, so update settings
+/// fetched with `Subreddit::settings`. Settings without a typed field are kept in
+/// `other` and sent back unchanged.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SubredditSettings {
+    /// The fullname of the subreddit
+    pub subreddit_id: String,
+    /// Title
+    pub title: Option<String>,
+    /// Short description shown in search results and the redesign
+    pub public_description: Option<String>,
+    /// Sidebar in **Markdown** format
+    pub description: Option<String>,
+    /// Text shown on the submission page
+    pub submit_text: Option<String>,
+    /// Custom label of the submit link button
+    pub submit_link_label: Option<String>,
+    /// Custom label of the submit text button
+    pub submit_text_label: Option<String>,
+    /// Who can view and submit
+    pub subreddit_type: Option<SubredditType>,
+    /// The kind of submissions allowed
+    #[serde(rename = "content_options")]
+    pub link_type: Option<LinkType>,
+    /// Spam filter strength for links
+    pub spam_links: Option<SpamFilter>,
+    /// Spam filter strength for self posts
+    pub spam_selfposts: Option<SpamFilter>,
+    /// Spam filter strength for comments
+    pub spam_comments: Option<SpamFilter>,
+    /// Who can edit the wiki
+    pub wikimode: Option<WikiMode>,
+    /// Account age in days required to edit the wiki
+    pub wiki_edit_age: Option<i64>,
+    /// Subreddit karma required to edit the wiki
+    pub wiki_edit_karma: Option<i64>,
+    /// Minutes comment scores are hidden for
+    pub comment_score_hide_mins: Option<u64>,
+    /// The suggested comment sort, empty for none
+    pub suggested_comment_sort: Option<String>,
+    /// Hover text of the header image
+    pub header_hover_text: Option<String>,
+    /// Language
+    pub language: Option<String>,
+    /// Theme color as hex code
+    pub key_color: Option<String>,
+    /// Message sent to new subscribers
+    pub welcome_message_text: Option<String>,
+    /// Whether new subscribers get a welcome message
+    pub welcome_message_enabled: Option<bool>,
+    /// Viewers must be over eighteen years old
+    pub over_18: Option<bool>,
+    /// Allow the subreddit to appear in r/all and default listings
+    #[serde(rename = "default_set")]
+    pub allow_top: Option<bool>,
+    /// Allow the subreddit to be recommended to new users
+    pub allow_discovery: Option<bool>,
+    /// Show thumbnails
+    pub show_media: Option<bool>,
+    /// Expand media previews on comment pages
+    pub show_media_preview: Option<bool>,
+    /// Collapse deleted and removed comments
+    pub collapse_deleted_comments: Option<bool>,
+    /// Allow spoiler tags
+    pub spoilers_enabled: Option<bool>,
+    /// Allow image uploads
+    pub allow_images: Option<bool>,
+    /// Allow video uploads
+    pub allow_videos: Option<bool>,
+    /// Allow polls
+    pub allow_polls: Option<bool>,
+    /// Allow crossposting submissions
+    pub allow_post_crossposts: Option<bool>,
+    /// Allow chat posts
+    pub allow_chat_post_creation: Option<bool>,
+    /// Allow free form reports
+    pub free_form_reports: Option<bool>,
+    /// Allow marking submissions as original content
+    pub original_content_tag_enabled: Option<bool>,
+    /// Mark all submissions as original content
+    pub all_original_content: Option<bool>,
+    /// Only approved users can submit
+    pub restrict_posting: Option<bool>,
+    /// Only approved users can comment
+    pub restrict_commenting: Option<bool>,
+    /// Stop accepting requests to become an approved user
+    pub disable_contributor_requests: Option<bool>,
+    /// Hide content of banned users from the mod queue
+    pub exclude_banned_modqueue: Option<bool>,
+    /// Collapse comments of users new to the subreddit
+    pub crowd_control_mode: Option<bool>,
+    /// Make the traffic stats public
+    pub public_traffic: Option<bool>,
+    /// Hide ads
+    pub hide_ads: Option<bool>,
+    /// Settings without a typed field
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl SubredditSettings {
+    /// Build the `api/site_admin` form for these settings.
+    pub fn build_form(&self) -> Vec<(String, String)> {
+        let mut form = vec![
+            (String::from("api_type"), String::from("json")),
+            (String::from("sr"), self.subreddit_id.to_owned()),
+        ];
+
+        let strings = [
+            ("title", &self.title),
+            ("public_description", &self.public_description),
+            ("description", &self.description),
+            ("submit_text", &self.submit_text),
+            ("submit_link_label", &self.submit_link_label),
+            ("submit_text_label", &self.submit_text_label),
+            ("suggested_comment_sort", &self.suggested_comment_sort),
+            ("header-title", &self.header_hover_text),
+            ("lang", &self.language),
+            ("key_color", &self.key_color),
+            ("welcome_message_text", &self.welcome_message_text),
+        ];
+
+        for (key, value) in strings {
+            if let Some(value) = value {
+                form.push((key.to_owned(), value.to_owned()));
+            }
+        }
+
+        let options = [
+            ("type", self.subreddit_type.as_ref().map(|t| t.as_str())),
+            ("link_type", self.link_type.as_ref().map(|t| t.as_str())),
+            ("spam_links", self.spam_links.as_ref().map(|s| s.as_str())),
+            (
+                "spam_selfposts",
+                self.spam_selfposts.as_ref().map(|s| s.as_str()),
+            ),
+            (
+                "spam_comments",
+                self.spam_comments.as_ref().map(|s| s.as_str()),
+            ),
+            ("wikimode", self.wikimode.as_ref().map(|w| w.as_str())),
+        ];
+
+        for (key, value) in options {
+            if let Some(value) = value {
+                form.push((key.to_owned(), value.to_owned()));
+            }
+        }
+
+        let numbers = [
+            ("wiki_edit_age", self.wiki_edit_age),
+            ("wiki_edit_karma", self.wiki_edit_karma),
+            (
+                "comment_score_hide_mins",
+                self.comment_score_hide_mins.map(|m| m as i64),
+            ),
+        ];
+
+        for (key, value) in numbers {
+            if let Some(value) = value {
+                form.push((key.to_owned(), value.to_string()));
+            }
+        }
+
+        let flags = [
+            ("welcome_message_enabled", self.welcome_message_enabled),
+            ("over_18", self.over_18),
+            ("allow_top", self.allow_top),
+            ("allow_discovery", self.allow_discovery),
+            ("show_media", self.show_media),
+            ("show_media_preview", self.show_media_preview),
+            ("collapse_deleted_comments", self.collapse_deleted_comments),
+            ("spoilers_enabled", self.spoilers_enabled),
+            ("allow_images", self.allow_images),
+            ("allow_videos", self.allow_videos),
+            ("allow_polls", self.allow_polls),
+            ("allow_post_crossposts", self.allow_post_crossposts),
+            ("allow_chat_post_creation", self.allow_chat_post_creation),
+            ("free_form_reports", self.free_form_reports),
+            (
+                "original_content_tag_enabled",
+                self.original_content_tag_enabled,
+            ),
+            ("all_original_content", self.all_original_content),
+            ("restrict_posting", self.restrict_posting),
+            ("restrict_commenting", self.restrict_commenting),
+            (
+                "disable_contributor_requests",
+                self.disable_contributor_requests,
+            ),
+            ("exclude_banned_modqueue", self.exclude_banned_modqueue),
+            ("crowd_control_mode", self.crowd_control_mode),
+            ("public_traffic", self.public_traffic),
+            ("hide_ads", self.hide_ads),
+        ];
+
+        for (key, value) in flags {
+            if let Some(value) = value {
+                form.push((key.to_owned(), value.to_string()));
+            }
+        }
+
+        for (key, value) in &self.other {
+            match value {
+                Value::Null => {}
+                Value::String(value) => form.push((key.to_owned(), value.to_owned())),
+                value => form.push((key.to_owned(), value.to_string())),
+            }
+        }
+
+        form
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinkType, SpamFilter, SubredditSettings, SubredditType, WikiMode};
+    use crate::models::response::BasicThing;
+
+    #[test]
+    fn test_settings_form() {
+        let json = r#"{
+            "kind": "subreddit_settings",
+            "data": {
+                "subreddit_id": "t5_2s7lj",
+                "title": "Rust",
+                "description": "Sidebar",
+                "subreddit_type": "public",
+                "content_options": "self",
+                "spam_links": "high",
+                "spam_comments": "low",
+                "wikimode": "approved_only",
+                "header_hover_text": "Hover",
+                "language": "en",
+                "default_set": true,
+                "over_18": false,
+                "wiki_edit_karma": 100,
+                "some_new_setting": 1,
+                "toxicity_threshold_chat_level": "low",
+                "new_but_unset": null
+            }
+        }"#;
+
+        let settings = serde_json::from_str::<BasicThing<SubredditSettings>>(json)
+            .unwrap()
+            .data;
+
+        assert_eq!(settings.subreddit_type, Some(SubredditType::Public));
+        assert_eq!(settings.link_type, Some(LinkType::SelfPost));
+        assert_eq!(settings.spam_links, Some(SpamFilter::High));
+        assert_eq!(
+            settings.wikimode,
+            Some(WikiMode::Other(String::from("approved_only")))
+        );
+        assert_eq!(settings.other.len(), 3);
+
+        let form = settings.build_form();
+        let value = |key: &str| form.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+
+        assert_eq!(value("sr"), Some("t5_2s7lj"));
+        assert_eq!(value("type"), Some("public"));
+        assert_eq!(value("link_type"), Some("self"));
+        assert_eq!(value("header-title"), Some("Hover"));
+        assert_eq!(value("lang"), Some("en"));
+        assert_eq!(value("allow_top"), Some("true"));
+        assert_eq!(value("over_18"), Some("false"));
+        assert_eq!(value("wiki_edit_karma"), Some("100"));
+        assert_eq!(value("wikimode"), Some("approved_only"));
+        assert_eq!(value("some_new_setting"), Some("1"));
+        assert_eq!(value("toxicity_threshold_chat_level"), Some("low"));
+        assert_eq!(value("new_but_unset"), None);
+    }
+}
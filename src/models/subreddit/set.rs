@@ -0,0 +1,332 @@
+//! # Subreddit Sets
+//! Combined feeds of several subreddits, e.g. `r/rust+programming`, and `r/all` or
+//! `r/popular` without some subreddits, e.g. `r/all-memes`.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::subreddit::set::{GeoRegion, SubredditSet};
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let combined = SubredditSet::new(&["rust", "programming"]).unwrap().subreddit();
+//!     let hot = combined.hot(25, None).await;
+//!
+//!     let popular = SubredditSet::popular()
+//!         .exclude("memes")
+//!         .unwrap()
+//!         .region(GeoRegion::Germany)
+//!         .unwrap()
+//!         .subreddit();
+//!     let hot = popular.hot(25, None).await;
+//! }
+//! ```
+use crate::client::Client;
+use crate::models::Subreddit;
+use crate::util::RouxError;
+
+/// A region to filter `r/popular` by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GeoRegion {
+    /// Everywhere
+    Global,
+    /// Argentina
+    Argentina,
+    /// Australia
+    Australia,
+    /// Bulgaria
+    Bulgaria,
+    /// Canada
+    Canada,
+    /// Chile
+    Chile,
+    /// Colombia
+    Colombia,
+    /// Croatia
+    Croatia,
+    /// Czech Republic
+    CzechRepublic,
+    /// Finland
+    Finland,
+    /// France
+    France,
+    /// Germany
+    Germany,
+    /// Greece
+    Greece,
+    /// Hungary
+    Hungary,
+    /// Iceland
+    Iceland,
+    /// India
+    India,
+    /// Ireland
+    Ireland,
+    /// Italy
+    Italy,
+    /// Japan
+    Japan,
+    /// Malaysia
+    Malaysia,
+    /// Mexico
+    Mexico,
+    /// New Zealand
+    NewZealand,
+    /// Philippines
+    Philippines,
+    /// Poland
+    Poland,
+    /// Portugal
+    Portugal,
+    /// Puerto Rico
+    PuertoRico,
+    /// Romania
+    Romania,
+    /// Serbia
+    Serbia,
+    /// Singapore
+    Singapore,
+    /// Spain
+    Spain,
+    /// Sweden
+    Sweden,
+    /// Taiwan
+    Taiwan,
+    /// Thailand
+    Thailand,
+    /// Turkey
+    Turkey,
+    /// United Kingdom
+    UnitedKingdom,
+    /// United States
+    UnitedStates,
+    /// A region roux does not know about yet, e.g. a US state like `US_WA`.
+    Other(String),
+}
+
+impl GeoRegion {
+    /// Gets the request string for the region
+    pub fn as_str(&self) -> &str {
+        match self {
+            GeoRegion::Global => "GLOBAL",
+            GeoRegion::Argentina => "AR",
+            GeoRegion::Australia => "AU",
+            GeoRegion::Bulgaria => "BG",
+            GeoRegion::Canada => "CA",
+            GeoRegion::Chile => "CL",
+            GeoRegion::Colombia => "CO",
+            GeoRegion::Croatia => "HR",
+            GeoRegion::CzechRepublic => "CZ",
+            GeoRegion::Finland => "FI",
+            GeoRegion::France => "FR",
+            GeoRegion::Germany => "DE",
+            GeoRegion::Greece => "GR",
+            GeoRegion::Hungary => "HU",
+            GeoRegion::Iceland => "IS",
+            GeoRegion::India => "IN",
+            GeoRegion::Ireland => "IE",
+            GeoRegion::Italy => "IT",
+            GeoRegion::Japan => "JP",
+            GeoRegion::Malaysia => "MY",
+            GeoRegion::Mexico => "MX",
+            GeoRegion::NewZealand => "NZ",
+            GeoRegion::Philippines => "PH",
+            GeoRegion::Poland => "PL",
+            GeoRegion::Portugal => "PT",
+            GeoRegion::PuertoRico => "PR",
+            GeoRegion::Romania => "RO",
+            GeoRegion::Serbia => "RS",
+            GeoRegion::Singapore => "SG",
+            GeoRegion::Spain => "ES",
+            GeoRegion::Sweden => "SE",
+            GeoRegion::Taiwan => "TW",
+            GeoRegion::Thailand => "TH",
+            GeoRegion::Turkey => "TR",
+            GeoRegion::UnitedKingdom => "GB",
+            GeoRegion::UnitedStates => "US",
+            GeoRegion::Other(region) => region,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SetKind {
+    Combined(Vec<String>),
+    All,
+    Popular,
+}
+
+/// Several subreddits read as one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubredditSet {
+    kind: SetKind,
+    excluded: Vec<String>,
+    region: Option<GeoRegion>,
+}
+
+/// Check that a subreddit name only contains letters, numbers and underscores, does not
+/// start with an underscore and is at most 21 characters long.
+#[allow(clippy::result_large_err)]
+pub fn validate_name(name: &str) -> Result<(), RouxError> {
+    let valid = (2..=21).contains(&name.len())
+        && !name.starts_with('_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(RouxError::InvalidSubredditName(name.to_owned()))
+    }
+}
+
+impl SubredditSet {
+    /// Combine subreddits into one feed, e.g. `r/rust+programming`.
+    #[allow(clippy::result_large_err)]
+    pub fn new(names: &[&str]) -> Result<SubredditSet, RouxError> {
+        if names.is_empty() {
+            return Err(RouxError::InvalidSubredditSet(String::from(
+                "a combined set needs at least one subreddit",
+            )));
+        }
+
+        for name in names {
+            validate_name(name)?;
+        }
+
+        Ok(SubredditSet {
+            kind: SetKind::Combined(names.iter().map(|name| name.to_string()).collect()),
+            excluded: vec![],
+            region: None,
+        })
+    }
+
+    /// `r/all`
+    pub fn all() -> SubredditSet {
+        SubredditSet {
+            kind: SetKind::All,
+            excluded: vec![],
+            region: None,
+        }
+    }
+
+    /// `r/popular`
+    pub fn popular() -> SubredditSet {
+        SubredditSet {
+            kind: SetKind::Popular,
+            excluded: vec![],
+            region: None,
+        }
+    }
+
+    /// Leave a subreddit out of `r/all` or `r/popular`, e.g. `r/all-memes`. Combined
+    /// subreddits cannot exclude any.
+    #[allow(clippy::result_large_err)]
+    pub fn exclude(mut self, name: &str) -> Result<SubredditSet, RouxError> {
+        validate_name(name)?;
+
+        if let SetKind::Combined(_) = self.kind {
+            return Err(RouxError::InvalidSubredditSet(String::from(
+                "only r/all and r/popular can exclude subreddits",
+            )));
+        }
+
+        self.excluded.push(name.to_owned());
+        Ok(self)
+    }
+
+    /// Filter `r/popular` by region. Other sets cannot be filtered by region.
+    #[allow(clippy::result_large_err)]
+    pub fn region(mut self, region: GeoRegion) -> Result<SubredditSet, RouxError> {
+        if self.kind != SetKind::Popular {
+            return Err(RouxError::InvalidSubredditSet(String::from(
+                "only r/popular can be filtered by region",
+            )));
+        }
+
+        self.region = Some(region);
+        Ok(self)
+    }
+
+    /// The name used in the URL, e.g. `rust+programming` or `all-memes`.
+    pub fn path(&self) -> String {
+        let mut path = match self.kind {
+            SetKind::Combined(ref names) => names.join("+"),
+            SetKind::All => String::from("all"),
+            SetKind::Popular => String::from("popular"),
+        };
+
+        for name in &self.excluded {
+            path.push('-');
+            path.push_str(name);
+        }
+
+        path
+    }
+
+    fn feed_params(&self) -> String {
+        match self.region {
+            Some(ref region) => format!("g={}&", region.as_str()),
+            None => String::new(),
+        }
+    }
+
+    /// Get a `Subreddit` to read the feeds of this set.
+    pub fn subreddit(&self) -> Subreddit {
+        let mut subreddit = Subreddit::new(&self.path());
+        subreddit.feed_params = self.feed_params();
+        subreddit
+    }
+
+    /// Get an authenticated `Subreddit` to read the feeds of this set using an oauth client
+    /// from the `Reddit` module.
+    pub fn subreddit_oauth(&self, client: &Client) -> Subreddit {
+        let mut subreddit = Subreddit::new_oauth(&self.path(), client);
+        subreddit.feed_params = self.feed_params();
+        subreddit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_name, GeoRegion, SubredditSet};
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("rust").is_ok());
+        assert!(validate_name("u_spez").is_ok());
+        assert!(validate_name("de").is_ok());
+        assert!(validate_name("rust+python").is_err());
+        assert!(validate_name("_rust").is_err());
+        assert!(validate_name("r/rust").is_err());
+        assert!(validate_name("averyveryverylongsubreddit").is_err());
+    }
+
+    #[test]
+    fn test_path() {
+        let combined = SubredditSet::new(&["rust", "programming"]).unwrap();
+        assert_eq!(combined.path(), "rust+programming");
+        assert!(combined.exclude("memes").is_err());
+        assert!(SubredditSet::new(&["rust", "all-memes"]).is_err());
+
+        let all = SubredditSet::all()
+            .exclude("memes")
+            .unwrap()
+            .exclude("funny")
+            .unwrap();
+        assert_eq!(all.path(), "all-memes-funny");
+
+        assert!(SubredditSet::new(&[]).is_err());
+
+        let popular = SubredditSet::popular()
+            .region(GeoRegion::UnitedStates)
+            .unwrap();
+        assert_eq!(popular.subreddit().feed_params, "g=US&");
+        assert!(SubredditSet::all().region(GeoRegion::Japan).is_err());
+        assert!(SubredditSet::new(&["rust"])
+            .unwrap()
+            .region(GeoRegion::Japan)
+            .is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! # Subreddit Traffic
+//! Traffic statistics of a subreddit.
+//!
+//! Enable the `chrono` or `time` feature to convert timestamps to their date types.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let subreddit = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .subreddit("rust")
+//!         .await
+//!         .unwrap();
+//!
+//!     let traffic = subreddit.traffic().await.unwrap();
+//!
+//!     // Compare the unique visitors of the last two months.
+//!     if let [this_month, last_month, ..] = &traffic.month[..] {
+//!         let growth = this_month.uniques as i64 - last_month.uniques as i64;
+//!     }
+//! }
+//! ```
+use std::convert::TryFrom;
+
+use serde::Deserialize;
+
+/// Traffic of a subreddit in one hour, day or month.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "Vec<i64>")]
+pub struct TrafficPoint {
+    /// Start of the period (UTC)
+    pub timestamp: i64,
+    /// Unique visitors
+    pub uniques: u64,
+    /// Page views
+    pub pageviews: u64,
+    /// New subscribers, only available for days
+    pub subscriptions: Option<u64>,
+}
+
+impl TryFrom<Vec<i64>> for TrafficPoint {
+    type Error = String;
+
+    fn try_from(values: Vec<i64>) -> Result<Self, Self::Error> {
+        let count = |value: i64| u64::try_from(value).map_err(|e| e.to_string());
+
+        match values[..] {
+            [timestamp, uniques, pageviews] => Ok(TrafficPoint {
+                timestamp,
+                uniques: count(uniques)?,
+                pageviews: count(pageviews)?,
+                subscriptions: None,
+            }),
+            [timestamp, uniques, pageviews, subscriptions, ..] => Ok(TrafficPoint {
+                timestamp,
+                uniques: count(uniques)?,
+                pageviews: count(pageviews)?,
+                subscriptions: Some(count(subscriptions)?),
+            }),
+            _ => Err(format!(
+                "expected at least 3 traffic values, got {}",
+                values.len()
+            )),
+        }
+    }
+}
+
+impl TrafficPoint {
+    /// Start of the period as a `chrono` date.
+    #[cfg(feature = "chrono")]
+    pub fn chrono_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.timestamp, 0)
+    }
+
+    /// Start of the period as a `time` date.
+    #[cfg(feature = "time")]
+    pub fn offset_datetime(&self) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::from_unix_timestamp(self.timestamp).ok()
+    }
+}
+
+/// Hourly, daily and monthly traffic of a subreddit, newest first.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SubredditTraffic {
+    /// Hourly traffic
+    #[serde(default)]
+    pub hour: Vec<TrafficPoint>,
+    /// Daily traffic
+    #[serde(default)]
+    pub day: Vec<TrafficPoint>,
+    /// Monthly traffic
+    #[serde(default)]
+    pub month: Vec<TrafficPoint>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SubredditTraffic, TrafficPoint};
+
+    #[test]
+    fn test_deserialize_traffic() {
+        let json = r#"{
+            "day": [[1700006400, 120, 900, 4], [1699920000, 100, 800, 0]],
+            "hour": [[1700010000, 10, 40]],
+            "month": [[1698796800, 3000, 20000]]
+        }"#;
+
+        let traffic = serde_json::from_str::<SubredditTraffic>(json).unwrap();
+
+        assert_eq!(
+            traffic.day[0],
+            TrafficPoint {
+                timestamp: 1700006400,
+                uniques: 120,
+                pageviews: 900,
+                subscriptions: Some(4),
+            }
+        );
+        assert_eq!(traffic.hour[0].subscriptions, None);
+        assert_eq!(traffic.month[0].pageviews, 20000);
+
+        assert!(serde_json::from_str::<SubredditTraffic>(r#"{"day": [[1, 2]]}"#).is_err());
+    }
+}
@@ -31,6 +31,7 @@ use crate::client::Client;
 use crate::util::defaults::default_client;
 use crate::util::{FeedOption, RouxError};
 
+use crate::models::multireddit::response::{LabeledMulti, MultiredditData};
 use crate::models::{About, Comments, Overview, Submissions};
 
 /// User.
@@ -120,6 +121,23 @@ impl User {
             .json::<About>()
             .await?)
     }
+
+    /// Get user's public multireddits.
+    #[maybe_async::maybe_async]
+    pub async fn multireddits(&self) -> Result<Vec<MultiredditData>, RouxError> {
+        let url = format!("https://www.reddit.com/api/multi/user/{}", self.user);
+
+        Ok(self
+            .client
+            .get(&url)
+            .send()
+            .await?
+            .json::<Vec<LabeledMulti>>()
+            .await?
+            .into_iter()
+            .map(|multi| multi.data)
+            .collect())
+    }
 }
 
 #[cfg(test)]
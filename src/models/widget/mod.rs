@@ -0,0 +1,596 @@
+//! # Widgets
+//! Typed sidebar widgets of a subreddit.
+//!
+//! Widgets can be serialized, so a sidebar can be kept in configuration files and deployed.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::widget::{TextAreaWidget, Widget};
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let subreddit = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .subreddit("rust")
+//!         .await
+//!         .unwrap();
+//!
+//!     let widget = Widget::TextArea(TextAreaWidget::new("About", "Posts about **Rust**."));
+//!     let created = subreddit.create_widget(&widget).await.unwrap();
+//!
+//!     // Move the new widget to the top of the sidebar.
+//!     let widgets = subreddit.widgets().await.unwrap();
+//!     let mut order: Vec<&str> = widgets.layout.sidebar.order.iter().map(String::as_str).collect();
+//!     if let Some(id) = created.id() {
+//!         order.retain(|widget| *widget != id);
+//!         order.insert(0, id);
+//!     }
+//!     subreddit.reorder_widgets(&order).await.unwrap();
+//! }
+//! ```
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Colors of a widget.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetStyles {
+    /// Background color as hex code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+    /// Header color as hex code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_color: Option<String>,
+}
+
+/// A widget with Markdown text.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextAreaWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Title
+    pub short_name: String,
+    /// Text in **Markdown** format
+    pub text: String,
+    /// Text in HTML format, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub text_html: Option<String>,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+impl TextAreaWidget {
+    /// Create a new `TextAreaWidget` instance.
+    pub fn new(short_name: &str, text: &str) -> TextAreaWidget {
+        TextAreaWidget {
+            id: None,
+            short_name: short_name.to_owned(),
+            text: text.to_owned(),
+            text_html: None,
+            styles: WidgetStyles::default(),
+        }
+    }
+}
+
+/// A button of a button widget.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetButton {
+    /// Either `text` or `image`
+    #[serde(default = "default_button_kind")]
+    pub kind: String,
+    /// Text
+    pub text: String,
+    /// Link
+    pub url: String,
+    /// Border color as hex code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Text color as hex code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+    /// Fill color as hex code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_color: Option<String>,
+}
+
+fn default_button_kind() -> String {
+    String::from("text")
+}
+
+impl WidgetButton {
+    /// Create a new text button.
+    pub fn new(text: &str, url: &str) -> WidgetButton {
+        WidgetButton {
+            kind: default_button_kind(),
+            text: text.to_owned(),
+            url: url.to_owned(),
+            color: None,
+            text_color: None,
+            fill_color: None,
+        }
+    }
+}
+
+/// A widget with buttons.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ButtonWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Title
+    pub short_name: String,
+    /// Description in **Markdown** format
+    #[serde(default)]
+    pub description: String,
+    /// Buttons
+    pub buttons: Vec<WidgetButton>,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// An image of an image widget.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetImage {
+    /// URL of the image, must be uploaded to Reddit
+    pub url: String,
+    /// Width
+    pub width: u32,
+    /// Height
+    pub height: u32,
+    /// Link opened when the image is clicked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_url: Option<String>,
+}
+
+/// A widget with images.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Title
+    pub short_name: String,
+    /// Images
+    pub data: Vec<WidgetImage>,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// What a calendar widget shows of each event.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarConfiguration {
+    /// Number of events shown
+    pub num_events: u32,
+    /// Show the date
+    pub show_date: bool,
+    /// Show the description
+    pub show_description: bool,
+    /// Show the location
+    pub show_location: bool,
+    /// Show the time
+    pub show_time: bool,
+    /// Show the title
+    pub show_title: bool,
+}
+
+/// A widget showing events of a Google calendar.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Title
+    pub short_name: String,
+    /// ID of the calendar, e.g. `xyz@group.calendar.google.com`
+    pub google_calendar_id: String,
+    /// Whether the calendar is synced with Google
+    #[serde(default)]
+    pub requires_sync: bool,
+    /// What is shown of each event
+    pub configuration: CalendarConfiguration,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CommunityListEntry {
+    Name(String),
+    #[serde(rename_all = "camelCase")]
+    Data {
+        name: String,
+        subscribers: Option<u64>,
+        icon_url: Option<String>,
+        is_subscribed: Option<bool>,
+    },
+}
+
+/// A subreddit in a community list widget. Only the name is sent to Reddit.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "CommunityListEntry")]
+pub struct CommunityListItem {
+    /// Name of the subreddit
+    pub name: String,
+    /// Subscribers, set by Reddit
+    pub subscribers: Option<u64>,
+    /// Icon, set by Reddit
+    pub icon_url: Option<String>,
+    /// Whether the user is subscribed, set by Reddit
+    pub is_subscribed: Option<bool>,
+}
+
+impl From<CommunityListEntry> for CommunityListItem {
+    fn from(entry: CommunityListEntry) -> Self {
+        match entry {
+            CommunityListEntry::Name(name) => CommunityListItem::new(&name),
+            CommunityListEntry::Data {
+                name,
+                subscribers,
+                icon_url,
+                is_subscribed,
+            } => CommunityListItem {
+                name,
+                subscribers,
+                icon_url,
+                is_subscribed,
+            },
+        }
+    }
+}
+
+impl Serialize for CommunityListItem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name)
+    }
+}
+
+impl CommunityListItem {
+    /// Create a new `CommunityListItem` instance.
+    pub fn new(name: &str) -> CommunityListItem {
+        CommunityListItem {
+            name: name.to_owned(),
+            subscribers: None,
+            icon_url: None,
+            is_subscribed: None,
+        }
+    }
+}
+
+/// A widget listing subreddits.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommunityListWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Title
+    pub short_name: String,
+    /// Subreddits
+    pub data: Vec<CommunityListItem>,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// A widget showing the subreddit rules.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RulesWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Title
+    pub short_name: String,
+    /// Either `full` or `compact`
+    #[serde(default = "default_rules_display")]
+    pub display: String,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+fn default_rules_display() -> String {
+    String::from("full")
+}
+
+/// A moderator in a moderators widget.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetModerator {
+    /// Name
+    pub name: String,
+    /// Author flair text
+    pub author_flair_text: Option<String>,
+}
+
+/// The widget listing the moderators. It exists once per subreddit and can only be restyled.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModeratorsWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Moderators shown, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub mods: Vec<WidgetModerator>,
+    /// Total number of moderators, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub total_mods: u64,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// An image usable in the CSS of a custom widget as `url(%%name%%)`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomWidgetImage {
+    /// Name
+    pub name: String,
+    /// URL of the image, must be uploaded to Reddit
+    pub url: String,
+    /// Width
+    pub width: u32,
+    /// Height
+    pub height: u32,
+}
+
+/// A widget with Markdown text and custom CSS.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Title
+    pub short_name: String,
+    /// Text in **Markdown** format
+    pub text: String,
+    /// CSS
+    pub css: String,
+    /// Height in pixels, between 50 and 500
+    pub height: u32,
+    /// Images usable in the CSS
+    #[serde(default)]
+    pub image_data: Vec<CustomWidgetImage>,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// A link of a menu widget.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MenuLink {
+    /// Text
+    pub text: String,
+    /// Link
+    pub url: String,
+}
+
+/// An entry of a menu widget, either a link or a submenu.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MenuItem {
+    /// A submenu
+    Submenu {
+        /// Text
+        text: String,
+        /// Links
+        children: Vec<MenuLink>,
+    },
+    /// A link
+    Link(MenuLink),
+}
+
+/// The menu shown at the top of the subreddit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Show a link to the wiki
+    #[serde(default)]
+    pub show_wiki: bool,
+    /// Entries
+    pub data: Vec<MenuItem>,
+}
+
+/// The widget describing the subreddit. It exists once per subreddit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdCardWidget {
+    /// ID, set by Reddit
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    /// Title
+    pub short_name: String,
+    /// Description
+    pub description: String,
+    /// What subscribers are called
+    pub subscribers_text: String,
+    /// What online users are called
+    pub currently_viewing_text: String,
+    /// Colors
+    #[serde(default)]
+    pub styles: WidgetStyles,
+}
+
+/// A sidebar or topbar widget.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Widget {
+    /// Markdown text
+    #[serde(rename = "textarea")]
+    TextArea(TextAreaWidget),
+    /// Buttons
+    #[serde(rename = "button")]
+    Button(ButtonWidget),
+    /// Images
+    #[serde(rename = "image")]
+    Image(ImageWidget),
+    /// Google calendar events
+    #[serde(rename = "calendar")]
+    Calendar(CalendarWidget),
+    /// List of subreddits
+    #[serde(rename = "community-list")]
+    CommunityList(CommunityListWidget),
+    /// Subreddit rules
+    #[serde(rename = "subreddit-rules")]
+    Rules(RulesWidget),
+    /// Moderators
+    #[serde(rename = "moderators")]
+    Moderators(ModeratorsWidget),
+    /// Markdown text with custom CSS
+    #[serde(rename = "custom")]
+    Custom(CustomWidget),
+    /// Topbar menu
+    #[serde(rename = "menu")]
+    Menu(MenuWidget),
+    /// Subreddit description
+    #[serde(rename = "id-card")]
+    IdCard(IdCardWidget),
+    /// A widget roux does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Widget {
+    /// The ID of the widget, if it was returned by Reddit.
+    pub fn id(&self) -> Option<&str> {
+        let id = match self {
+            Widget::TextArea(widget) => &widget.id,
+            Widget::Button(widget) => &widget.id,
+            Widget::Image(widget) => &widget.id,
+            Widget::Calendar(widget) => &widget.id,
+            Widget::CommunityList(widget) => &widget.id,
+            Widget::Rules(widget) => &widget.id,
+            Widget::Moderators(widget) => &widget.id,
+            Widget::Custom(widget) => &widget.id,
+            Widget::Menu(widget) => &widget.id,
+            Widget::IdCard(widget) => &widget.id,
+            Widget::Unknown => return None,
+        };
+
+        id.as_deref()
+    }
+}
+
+/// The widget IDs of a section, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct WidgetOrder {
+    /// Widget IDs
+    #[serde(default)]
+    pub order: Vec<String>,
+}
+
+/// Where widgets are placed.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetLayout {
+    /// ID of the id card widget
+    pub id_card_widget: Option<String>,
+    /// ID of the moderators widget
+    pub moderator_widget: Option<String>,
+    /// Sidebar widgets
+    #[serde(default)]
+    pub sidebar: WidgetOrder,
+    /// Topbar widgets, i.e. the menu
+    #[serde(default)]
+    pub topbar: WidgetOrder,
+}
+
+/// The widgets of a subreddit.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Widgets {
+    /// Widgets by ID
+    pub items: HashMap<String, Widget>,
+    /// Layout
+    pub layout: WidgetLayout,
+}
+
+impl Widgets {
+    /// Get the sidebar widgets in order.
+    pub fn sidebar(&self) -> Vec<&Widget> {
+        self.layout
+            .sidebar
+            .order
+            .iter()
+            .filter_map(|id| self.items.get(id))
+            .collect()
+    }
+
+    /// Get the topbar widgets in order.
+    pub fn topbar(&self) -> Vec<&Widget> {
+        self.layout
+            .topbar
+            .order
+            .iter()
+            .filter_map(|id| self.items.get(id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommunityListItem, TextAreaWidget, Widget, Widgets};
+
+    #[test]
+    fn test_deserialize_widgets() {
+        let json = r#"{
+            "items": {
+                "widget_1": {"kind": "textarea", "id": "widget_1", "shortName": "About", "text": "Hi", "textHtml": "<p>Hi</p>", "styles": {"backgroundColor": "", "headerColor": ""}},
+                "widget_2": {"kind": "community-list", "id": "widget_2", "shortName": "Friends", "data": [{"name": "learnrust", "subscribers": 10, "iconUrl": "", "isSubscribed": false}]},
+                "widget_3": {"kind": "moderators", "id": "widget_3", "mods": [{"name": "spez", "authorFlairText": null}], "totalMods": 1},
+                "widget_4": {"kind": "post-flair", "id": "widget_4"}
+            },
+            "layout": {
+                "idCardWidget": "widget_0",
+                "moderatorWidget": "widget_3",
+                "sidebar": {"order": ["widget_2", "widget_1", "widget_4"]},
+                "topbar": {"order": []}
+            }
+        }"#;
+
+        let widgets = serde_json::from_str::<Widgets>(json).unwrap();
+        let sidebar = widgets.sidebar();
+
+        match sidebar[0] {
+            Widget::CommunityList(list) => assert_eq!(list.data[0].subscribers, Some(10)),
+            _ => panic!("expected a community list"),
+        }
+
+        assert_eq!(sidebar[1].id(), Some("widget_1"));
+        assert_eq!(sidebar[2], &Widget::Unknown);
+    }
+
+    #[test]
+    fn test_serialize_widget() {
+        let widget = Widget::TextArea(TextAreaWidget::new("About", "Hi"));
+        assert_eq!(
+            serde_json::to_value(&widget).unwrap(),
+            serde_json::json!({"kind": "textarea", "shortName": "About", "text": "Hi", "styles": {}})
+        );
+
+        let item = CommunityListItem::new("learnrust");
+        assert_eq!(serde_json::to_string(&item).unwrap(), r#""learnrust""#);
+    }
+}
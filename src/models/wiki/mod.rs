@@ -0,0 +1,292 @@
+//! # Wiki
+//! Read and edit the wiki of a subreddit.
+//!
+//! # Usage
+//! ```no_run
+//! use roux::Reddit;
+//! use roux::util::RouxError;
+//! #[cfg(feature = "async")]
+//! use tokio;
+//!
+//! #[cfg_attr(feature = "async", tokio::main)]
+//! #[maybe_async::maybe_async]
+//! async fn main() {
+//!     let subreddit = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!         .username("USERNAME")
+//!         .password("PASSWORD")
+//!         .subreddit("rust")
+//!         .await
+//!         .unwrap();
+//!
+//!     let wiki = subreddit.wiki();
+//!     let page = wiki.page("config/automoderator", None).await.unwrap();
+//!
+//!     // Only save if nobody else edited the page in the meantime.
+//!     let content = format!("{}\n---\n", page.data.content_md);
+//!     match wiki
+//!         .edit("config/automoderator", &content, Some("bot"), page.data.revision_id.as_deref())
+//!         .await
+//!     {
+//!         Err(RouxError::WikiConflict(conflict)) => println!("{}", conflict.newrevision),
+//!         _ => {}
+//!     }
+//! }
+//! ```
+
+pub mod response;
+
+extern crate serde_json;
+use serde::Serialize;
+
+use crate::client::{Client, Response};
+use crate::models::wiki::response::{
+    WikiEditConflict, WikiPage, WikiPageSettings, WikiPages, WikiRevisions,
+};
+use crate::util::{FeedOption, RouxError};
+
+/// Who may edit a wiki page.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WikiPermLevel {
+    /// Use the subreddit's wiki settings
+    Inherit,
+    /// Only approved wiki contributors
+    Contributors,
+    /// Only moderators
+    Moderators,
+}
+
+impl WikiPermLevel {
+    /// Gets the request string for the permission level
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WikiPermLevel::Inherit => "0",
+            WikiPermLevel::Contributors => "1",
+            WikiPermLevel::Moderators => "2",
+        }
+    }
+}
+
+/// Wiki
+pub struct Wiki {
+    /// Name of subreddit.
+    pub subreddit: String,
+    url: String,
+    client: Client,
+}
+
+impl Wiki {
+    /// Create a new `Wiki` instance from a subreddit url.
+    pub(crate) fn new(subreddit: &str, url: &str, client: &Client) -> Wiki {
+        Wiki {
+            subreddit: subreddit.to_owned(),
+            url: url.to_owned(),
+            client: client.to_owned(),
+        }
+    }
+
+    /// Turns an edit conflict into `RouxError::WikiConflict` and other failures into
+    /// `RouxError::Status`.
+    #[maybe_async::maybe_async]
+    async fn check(response: Response) -> Result<Response, RouxError> {
+        if response.status() == 409 {
+            Err(RouxError::WikiConflict(
+                response.json::<WikiEditConflict>().await?,
+            ))
+        } else if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(RouxError::Status(response))
+        }
+    }
+
+    #[maybe_async::maybe_async]
+    async fn get(&self, url: &str) -> Result<Response, RouxError> {
+        Self::check(self.client.get(url).send().await?).await
+    }
+
+    #[maybe_async::maybe_async]
+    async fn post<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
+        let post_url = format!("{}/{}", self.url, url);
+
+        Self::check(self.client.post(post_url).form(&form).send().await?).await
+    }
+
+    /// Get the names of all wiki pages.
+    #[maybe_async::maybe_async]
+    pub async fn pages(&self) -> Result<WikiPages, RouxError> {
+        Ok(self
+            .get(&format!("{}/wiki/pages/.json", self.url))
+            .await?
+            .json::<WikiPages>()
+            .await?)
+    }
+
+    /// Get a wiki page, optionally at a specific revision.
+    #[maybe_async::maybe_async]
+    pub async fn page(&self, page: &str, revision: Option<&str>) -> Result<WikiPage, RouxError> {
+        let url = &mut format!("{}/wiki/{}/.json", self.url, page);
+
+        if let Some(revision) = revision {
+            url.push_str(&format!("?v={}", revision));
+        }
+
+        Ok(self.get(url).await?.json::<WikiPage>().await?)
+    }
+
+    /// Edit a wiki page. If `previous` is the ID of the revision the edit is based on and
+    /// the page has been changed since, the edit is rejected with `RouxError::WikiConflict`.
+    #[maybe_async::maybe_async]
+    pub async fn edit(
+        &self,
+        page: &str,
+        content: &str,
+        reason: Option<&str>,
+        previous: Option<&str>,
+    ) -> Result<(), RouxError> {
+        let mut form = vec![("page", page), ("content", content)];
+
+        if let Some(reason) = reason {
+            form.push(("reason", reason));
+        }
+
+        if let Some(previous) = previous {
+            form.push(("previous", previous));
+        }
+
+        self.post("api/wiki/edit", &form).await?;
+
+        Ok(())
+    }
+
+    /// Get the revisions of a wiki page, or of all pages if `page` is `None`.
+    #[maybe_async::maybe_async]
+    pub async fn revisions(
+        &self,
+        page: Option<&str>,
+        options: Option<FeedOption>,
+    ) -> Result<WikiRevisions, RouxError> {
+        let url = &mut match page {
+            Some(page) => format!("{}/wiki/revisions/{}/.json", self.url, page),
+            None => format!("{}/wiki/revisions/.json", self.url),
+        };
+
+        if let Some(options) = options {
+            options.build_url(url);
+        }
+
+        Ok(self.get(url).await?.json::<WikiRevisions>().await?)
+    }
+
+    /// Revert a wiki page to a revision.
+    #[maybe_async::maybe_async]
+    pub async fn revert(&self, page: &str, revision: &str) -> Result<(), RouxError> {
+        let form = [("page", page), ("revision", revision)];
+        self.post("api/wiki/revert", &form).await?;
+
+        Ok(())
+    }
+
+    /// Hide or unhide a revision from the revision history.
+    #[maybe_async::maybe_async]
+    pub async fn toggle_revision_hidden(
+        &self,
+        page: &str,
+        revision: &str,
+    ) -> Result<(), RouxError> {
+        let form = [("page", page), ("revision", revision)];
+        self.post("api/wiki/hide", &form).await?;
+
+        Ok(())
+    }
+
+    /// Get the settings of a wiki page.
+    #[maybe_async::maybe_async]
+    pub async fn settings(&self, page: &str) -> Result<WikiPageSettings, RouxError> {
+        Ok(self
+            .get(&format!("{}/wiki/settings/{}/.json", self.url, page))
+            .await?
+            .json::<WikiPageSettings>()
+            .await?)
+    }
+
+    /// Update the settings of a wiki page.
+    #[maybe_async::maybe_async]
+    pub async fn update_settings(
+        &self,
+        page: &str,
+        permlevel: WikiPermLevel,
+        listed: bool,
+    ) -> Result<WikiPageSettings, RouxError> {
+        let form = [
+            ("permlevel", permlevel.as_str()),
+            ("listed", if listed { "true" } else { "false" }),
+        ];
+
+        Ok(self
+            .post(&format!("wiki/settings/{}", page), &form)
+            .await?
+            .json::<WikiPageSettings>()
+            .await?)
+    }
+
+    /// Allow a user to edit a wiki page.
+    #[maybe_async::maybe_async]
+    pub async fn add_editor(&self, page: &str, username: &str) -> Result<(), RouxError> {
+        let form = [("page", page), ("username", username)];
+        self.post("api/wiki/alloweditor/add", &form).await?;
+
+        Ok(())
+    }
+
+    /// Remove a user from the editors of a wiki page.
+    #[maybe_async::maybe_async]
+    pub async fn remove_editor(&self, page: &str, username: &str) -> Result<(), RouxError> {
+        let form = [("page", page), ("username", username)];
+        self.post("api/wiki/alloweditor/del", &form).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Wiki;
+    use crate::client::Response;
+    use crate::util::RouxError;
+
+    fn response(status: u16, body: &str) -> Response {
+        http::Response::builder()
+            .status(status)
+            .body(body.to_owned())
+            .unwrap()
+            .into()
+    }
+
+    #[maybe_async::test(feature = "blocking", async(not(feature = "blocking"), tokio::test))]
+    async fn test_check() {
+        let ok = Wiki::check(response(200, "{}")).await;
+        assert!(ok.is_ok());
+
+        let conflict = r#"{
+            "newcontent": "current",
+            "newrevision": "5ea2c4e6-0d9c-11ee-9d51-4e6c1d3a41a7",
+            "diffcontent": "<ins>current</ins>",
+            "reason": "EDIT_CONFLICT",
+            "message": "Conflict"
+        }"#;
+
+        match Wiki::check(response(409, conflict)).await {
+            Err(RouxError::WikiConflict(conflict)) => {
+                assert_eq!(conflict.newcontent, "current");
+                assert_eq!(conflict.newrevision, "5ea2c4e6-0d9c-11ee-9d51-4e6c1d3a41a7");
+                assert_eq!(conflict.reason.as_deref(), Some("EDIT_CONFLICT"));
+            }
+            _ => panic!("expected a wiki conflict"),
+        }
+
+        match Wiki::check(response(404, r#"{"message": "Not Found"}"#)).await {
+            Err(RouxError::Status(response)) => assert_eq!(response.status(), 404),
+            _ => panic!("expected a status error"),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! # Wiki Responses
+use serde::Deserialize;
+
+use crate::models::about::About;
+use crate::models::response::{BasicThing, Listing};
+
+pub use crate::util::WikiEditConflict;
+
+/// WikiPageData
+#[derive(Debug, Deserialize)]
+pub struct WikiPageData {
+    /// Content in **Markdown** format
+    pub content_md: String,
+    /// Content in HTML format
+    pub content_html: Option<String>,
+    /// Whether the logged-in user may edit the page
+    pub may_revise: Option<bool>,
+    /// ID of the revision
+    pub revision_id: Option<String>,
+    /// The user that made the revision
+    pub revision_by: Option<About>,
+    /// When the revision was made (UTC)
+    pub revision_date: Option<f64>,
+    /// The reason given for the revision
+    pub reason: Option<String>,
+}
+
+/// WikiPage
+pub type WikiPage = BasicThing<WikiPageData>;
+
+/// The names of all wiki pages of a subreddit.
+pub type WikiPages = BasicThing<Vec<String>>;
+
+/// WikiRevisionData
+#[derive(Debug, Deserialize)]
+pub struct WikiRevisionData {
+    /// ID of the revision
+    pub id: String,
+    /// The page the revision was made on
+    pub page: String,
+    /// The reason given for the revision
+    pub reason: Option<String>,
+    /// When the revision was made (UTC)
+    pub timestamp: f64,
+    /// The user that made the revision
+    pub author: Option<About>,
+    /// Whether the revision is hidden
+    pub revision_hidden: Option<bool>,
+}
+
+/// WikiRevisions
+pub type WikiRevisions = BasicThing<Listing<WikiRevisionData>>;
+
+/// WikiPageSettingsData
+#[derive(Debug, Deserialize)]
+pub struct WikiPageSettingsData {
+    /// Who may edit the page: `0` uses the subreddit's wiki settings, `1` only approved
+    /// wiki contributors, `2` only moderators.
+    pub permlevel: u8,
+    /// Whether the page is shown in the page listing
+    pub listed: bool,
+    /// Users allowed to edit the page
+    #[serde(default)]
+    pub editors: Vec<About>,
+}
+
+/// WikiPageSettings
+pub type WikiPageSettings = BasicThing<WikiPageSettingsData>;
+
+#[cfg(test)]
+mod tests {
+    use super::{WikiPage, WikiRevisions};
+
+    #[test]
+    fn test_page_and_revisions() {
+        let page: WikiPage = serde_json::from_str(
+            r##"{"kind": "wikipage", "data": {
+                "content_md": "# Rules", "content_html": "<h1>Rules</h1>", "may_revise": true,
+                "revision_id": "5ea2c4e6", "revision_by": {"kind": "t2", "data": {"name": "spez"}},
+                "revision_date": 1687000000, "reason": null
+            }}"##,
+        )
+        .unwrap();
+        assert_eq!(page.data.content_md, "# Rules");
+        assert_eq!(page.data.revision_id.as_deref(), Some("5ea2c4e6"));
+        assert_eq!(
+            page.data.revision_by.unwrap().data.name.as_deref(),
+            Some("spez")
+        );
+
+        let revisions: WikiRevisions = serde_json::from_str(
+            r#"{"kind": "Listing", "data": {"after": null, "before": null, "children": [
+                {"id": "5ea2c4e6", "page": "index", "reason": "typo", "timestamp": 1687000000,
+                "author": null, "revision_hidden": false}
+            ]}}"#,
+        )
+        .unwrap();
+        assert_eq!(revisions.data.children[0].page, "index");
+    }
+}
@@ -1,10 +1,75 @@
 use std::error;
 use std::fmt;
 
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
 use serde_json;
+use serde_json::Value;
 
 use crate::client;
 
+/// An error returned by Reddit in the body of an `api_type=json` response.
+/// Reddit sends these as `[code, message, field]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// The error code, e.g. `USER_DOESNT_EXIST`.
+    pub code: String,
+    /// A human readable description of the error.
+    pub message: String,
+    /// The form field the error relates to, if any.
+    pub field: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for ApiError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<Value>::deserialize(deserializer)?;
+
+        let code = values
+            .first()
+            .and_then(Value::as_str)
+            .ok_or_else(|| de::Error::custom("api error is missing a code"))?;
+
+        Ok(ApiError {
+            code: code.to_owned(),
+            message: values
+                .get(1)
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_owned(),
+            field: values
+                .get(2)
+                .and_then(Value::as_str)
+                .filter(|field| !field.is_empty())
+                .map(str::to_owned),
+        })
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.field {
+            Some(ref field) => write!(f, "{}: {} ({})", self.code, self.message, field),
+            None => write!(f, "{}: {}", self.code, self.message),
+        }
+    }
+}
+
+/// The body of a rejected wiki edit, returned when the page was changed since the
+/// `previous` revision.
+#[derive(Debug, Deserialize)]
+pub struct WikiEditConflict {
+    /// The current content of the page
+    pub newcontent: String,
+    /// ID of the current revision of the page
+    pub newrevision: String,
+    /// HTML diff between the current content and the rejected edit
+    pub diffcontent: Option<String>,
+    /// The reason, usually `EDIT_CONFLICT`
+    pub reason: Option<String>,
+    /// A description of the error
+    pub message: Option<String>,
+}
+
 /// Error type that occurs when an API request fails for some reason.
 #[derive(Debug)]
 pub enum RouxError {
@@ -17,6 +82,17 @@ pub enum RouxError {
     Parse(serde_json::Error),
     /// Occurs if there is a grant error.
     Auth(String),
+    /// Occurs if Reddit accepted the request but reported errors in the response body.
+    Api(Vec<ApiError>),
+    /// Occurs if a wiki edit was rejected because the page was changed since the
+    /// revision the edit was based on.
+    WikiConflict(WikiEditConflict),
+    /// Occurs if a subreddit name is not valid.
+    InvalidSubredditName(String),
+    /// Occurs if a `SubredditSet` is built in a way Reddit does not support.
+    InvalidSubredditSet(String),
+    /// Occurs if a successful response is missing data it should contain.
+    MissingData(String),
 }
 
 impl From<client::Error> for RouxError {
@@ -38,6 +114,30 @@ impl fmt::Display for RouxError {
             RouxError::Network(ref err) => err.fmt(f),
             RouxError::Parse(ref err) => err.fmt(f),
             RouxError::Auth(ref err) => write!(f, "Auth error: {}", err),
+            RouxError::Api(ref errs) => {
+                write!(f, "API error: ")?;
+
+                for (i, err) in errs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", err)?;
+                }
+
+                Ok(())
+            }
+            RouxError::WikiConflict(ref conflict) => write!(
+                f,
+                "Wiki edit conflict: page changed in revision {}",
+                conflict.newrevision
+            ),
+            RouxError::InvalidSubredditName(ref name) => {
+                write!(f, "Invalid subreddit name: {:?}", name)
+            }
+            RouxError::InvalidSubredditSet(ref reason) => {
+                write!(f, "Invalid subreddit set: {}", reason)
+            }
+            RouxError::MissingData(ref what) => write!(f, "Missing data: {}", what),
         }
     }
 }
@@ -47,6 +147,11 @@ impl error::Error for RouxError {
         match *self {
             RouxError::Status(_) => None,
             RouxError::Auth(_) => None,
+            RouxError::Api(_) => None,
+            RouxError::WikiConflict(_) => None,
+            RouxError::InvalidSubredditName(_) => None,
+            RouxError::InvalidSubredditSet(_) => None,
+            RouxError::MissingData(_) => None,
             RouxError::Network(ref err) => Some(err),
             RouxError::Parse(ref err) => Some(err),
         }
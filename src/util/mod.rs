@@ -4,8 +4,9 @@ pub mod defaults;
 pub mod error;
 /// Url building.
 pub mod url;
-pub use error::RouxError;
+pub use error::{ApiError, RouxError, WikiEditConflict};
 /// Options
 pub mod option;
 pub use option::FeedOption;
+pub use option::SubmitOption;
 pub use option::TimePeriod;
@@ -107,6 +107,81 @@ impl Default for FeedOption {
     }
 }
 
+/// Options for submitting a post.
+#[derive(Clone, Debug, Default)]
+pub struct SubmitOption {
+    /// Mark the submission as NSFW.
+    pub nsfw: Option<bool>,
+    /// Mark the submission as a spoiler.
+    pub spoiler: Option<bool>,
+    /// Send inbox replies for the submission.
+    pub send_replies: Option<bool>,
+    /// The link flair template to apply.
+    pub flair_id: Option<String>,
+    /// The link flair text to apply (only works with an editable flair template).
+    pub flair_text: Option<String>,
+}
+
+impl SubmitOption {
+    /// Create a new `SubmitOption` instance.
+    pub fn new() -> SubmitOption {
+        SubmitOption::default()
+    }
+
+    /// Set nsfw param.
+    pub fn nsfw(mut self, nsfw: bool) -> SubmitOption {
+        self.nsfw = Some(nsfw);
+        self
+    }
+
+    /// Set spoiler param.
+    pub fn spoiler(mut self, spoiler: bool) -> SubmitOption {
+        self.spoiler = Some(spoiler);
+        self
+    }
+
+    /// Set sendreplies param.
+    pub fn send_replies(mut self, send_replies: bool) -> SubmitOption {
+        self.send_replies = Some(send_replies);
+        self
+    }
+
+    /// Set flair_id param.
+    pub fn flair_id(mut self, flair_id: &str) -> SubmitOption {
+        self.flair_id = Some(flair_id.to_owned());
+        self
+    }
+
+    /// Set flair_text param.
+    pub fn flair_text(mut self, flair_text: &str) -> SubmitOption {
+        self.flair_text = Some(flair_text.to_owned());
+        self
+    }
+
+    /// Add the set options to a submission form.
+    pub fn build_form(self, form: &mut Vec<(&str, String)>) {
+        if let Some(nsfw) = self.nsfw {
+            form.push(("nsfw", nsfw.to_string()));
+        }
+
+        if let Some(spoiler) = self.spoiler {
+            form.push(("spoiler", spoiler.to_string()));
+        }
+
+        if let Some(send_replies) = self.send_replies {
+            form.push(("sendreplies", send_replies.to_string()));
+        }
+
+        if let Some(flair_id) = self.flair_id {
+            form.push(("flair_id", flair_id));
+        }
+
+        if let Some(flair_text) = self.flair_text {
+            form.push(("flair_text", flair_text));
+        }
+    }
+}
+
 /// Allows you to request a certain time period. This only works in certain situations, like when asking for top of a subreddit
 #[derive(Copy, Clone, Debug)]
 pub enum TimePeriod {
@@ -140,7 +215,7 @@ impl TimePeriod {
 
 #[cfg(test)]
 mod tests {
-    use super::FeedOption;
+    use super::{FeedOption, SubmitOption};
 
     #[test]
     fn test_build_url_after() {
@@ -174,4 +249,25 @@ mod tests {
 
         assert!(*url == format!("?&count={}&", count))
     }
+
+    #[test]
+    fn test_build_form_submit_option() {
+        let options = SubmitOption::new()
+            .nsfw(true)
+            .send_replies(false)
+            .flair_id("some_flair");
+
+        let mut form = vec![("kind", String::from("crosspost"))];
+        options.build_form(&mut form);
+
+        assert_eq!(
+            form,
+            vec![
+                ("kind", String::from("crosspost")),
+                ("nsfw", String::from("true")),
+                ("sendreplies", String::from("false")),
+                ("flair_id", String::from("some_flair")),
+            ]
+        );
+    }
 }
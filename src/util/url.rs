@@ -7,3 +7,36 @@ pub fn build_url(dest: &str) -> String {
 pub fn build_oauth(dest: &str) -> String {
     format!("https://oauth.reddit.com/{}/.json", dest)
 }
+
+/// Builds a url for OAuth Reddit access to endpoints that do not take a `.json` suffix.
+pub fn build_oauth_endpoint(dest: &str) -> String {
+    format!("https://oauth.reddit.com/{}", dest)
+}
+
+/// Percent-encodes a value for use in a query string.
+pub fn encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode("rust-lang_1.0~"), "rust-lang_1.0~");
+        assert_eq!(encode("title:\"a b\" & c"), "title%3A%22a%20b%22%20%26%20c");
+        assert_eq!(encode("ü"), "%C3%BC");
+    }
+}